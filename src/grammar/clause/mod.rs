@@ -1,10 +1,17 @@
 //! Clauses.
 
+pub mod on_conflict;
 pub mod order_by;
+pub mod returning;
 #[path = "where.rs"] pub mod so_that;
 
+pub use self::on_conflict::Excluded;
+pub use self::on_conflict::OnConflict;
+
 pub use self::order_by::Order;
 pub use self::order_by::OrderBy;
 pub use self::order_by::Orderable;
 
+pub use self::returning::Returning;
+
 pub use self::so_that::Where;
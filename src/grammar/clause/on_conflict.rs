@@ -0,0 +1,166 @@
+//! The `ON CONFLICT` clause.
+
+use grammar::clause::Where;
+use grammar::{Buffer, Clause, Condition, Context, Expression};
+use {Dialect, Result};
+
+/// An `ON CONFLICT` clause.
+#[derive(Debug, Default)]
+pub struct OnConflict {
+    columns: Option<Vec<String>>,
+    update: Option<Vec<(String, Box<Expression>)>>,
+    nothing: Option<()>,
+    so_that: Option<Where>,
+}
+
+/// A reference to a value that was proposed for insertion.
+#[derive(Clone, Debug)]
+pub struct Excluded(String);
+
+impl OnConflict {
+    /// Create an `ON CONFLICT` clause.
+    pub fn new<T: ToString>(columns: &[T]) -> Self {
+        let mut clause = OnConflict::default();
+        for column in columns {
+            push!(clause.columns, column.to_string());
+        }
+        clause
+    }
+
+    /// Mark that conflicting rows should be left intact.
+    pub fn do_nothing(mut self) -> Self {
+        self.nothing = Some(());
+        self.update = None;
+        self
+    }
+
+    /// Mark that conflicting rows should be updated.
+    pub fn do_update(mut self) -> Self {
+        self.nothing = None;
+        if self.update.is_none() {
+            self.update = Some(vec![]);
+        }
+        self
+    }
+
+    /// Add an assignment to the update.
+    pub fn set<T: ToString, U>(mut self, column: T, value: U) -> Self
+        where U: Expression + 'static
+    {
+        self.nothing = None;
+        push!(self.update, (column.to_string(), Box::new(value)));
+        self
+    }
+
+    /// Add a condition to the update.
+    pub fn so_that<T>(mut self, condition: T) -> Self where T: Condition + 'static {
+        self.so_that = Some(match self.so_that.take() {
+            Some(so_that) => so_that.and(condition),
+            _ => Where::default().and(condition),
+        });
+        self
+    }
+}
+
+impl Clause for OnConflict {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let dialect = context.dialect();
+        let mut buffer = Buffer::new();
+        if dialect == Dialect::MySQL {
+            if let Some(_) = self.nothing {
+                raise!("expected DO UPDATE in MySQL");
+            }
+            if let Some(_) = self.so_that {
+                raise!("expected no conditional updates in MySQL");
+            }
+            buffer.push("ON DUPLICATE KEY UPDATE");
+        } else {
+            buffer.push("ON CONFLICT");
+            if let Some(ref columns) = self.columns {
+                buffer.push({
+                    let mut buffer = Buffer::new();
+                    for column in columns {
+                        buffer.push(context.quote(column));
+                    }
+                    format!("({})", buffer.join(", "))
+                });
+            }
+            if let Some(_) = self.nothing {
+                if let Some(_) = self.so_that {
+                    raise!("expected no condition for DO NOTHING");
+                }
+                buffer.push("DO NOTHING");
+                return Ok(buffer.join(" "));
+            }
+            if self.columns.is_none() {
+                raise!("expected “columns” to be set for DO UPDATE");
+            }
+            buffer.push("DO UPDATE SET");
+        }
+        let assignments = some!(self.update);
+        if assignments.is_empty() {
+            raise!("expected at least one assignment for DO UPDATE");
+        }
+        buffer.push({
+            let mut buffer = Buffer::new();
+            for &(ref column, ref value) in assignments {
+                buffer.push(format!("{} = {}", context.quote(column),
+                                               try!(value.compile_with(context))));
+            }
+            buffer.join(", ")
+        });
+        if let Some(ref clause) = self.so_that {
+            buffer.push(try!(clause.compile_with(context)));
+        }
+        Ok(buffer.join(" "))
+    }
+}
+
+impl Excluded {
+    /// Create a reference to a value that was proposed for insertion.
+    #[inline]
+    pub fn new<T: ToString>(column: T) -> Self {
+        Excluded(column.to_string())
+    }
+}
+
+impl Expression for Excluded {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        Ok(match context.dialect() {
+            Dialect::MySQL => format!("VALUES({})", context.quote(&self.0)),
+            _ => format!("excluded.{}", context.quote(&self.0)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Clause, Context};
+    use prelude::*;
+
+    #[test]
+    fn do_nothing() {
+        let clause = on_conflict(&["foo"]).do_nothing();
+        assert_eq!(clause.compile().unwrap(), "ON CONFLICT (`foo`) DO NOTHING");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(clause.compile_with(&mut context).is_err());
+
+        let clause = on_conflict(&["foo"]).do_nothing().so_that(column("bar").equal(1));
+        assert!(clause.compile().is_err());
+    }
+
+    #[test]
+    fn do_update() {
+        let clause = on_conflict(&["foo"]).do_update().set("bar", excluded("bar"))
+                                                      .so_that(column("baz").equal(1));
+
+        assert_eq!(clause.compile().unwrap(),
+                   "ON CONFLICT (`foo`) DO UPDATE SET `bar` = excluded.`bar` WHERE `baz` = 1");
+
+        let clause = on_conflict(&["foo"]).do_update().set("bar", excluded("bar"));
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(clause.compile_with(&mut context).unwrap(),
+                   "ON DUPLICATE KEY UPDATE `bar` = VALUES(`bar`)");
+    }
+}
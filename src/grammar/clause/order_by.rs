@@ -2,7 +2,7 @@
 
 use Result;
 use grammar::definition::Column;
-use grammar::{Buffer, Clause, Expression};
+use grammar::{Buffer, Clause, Context, Expression};
 
 /// An `ORDER BY` clause.
 #[derive(Debug, Default)]
@@ -45,10 +45,10 @@ impl OrderBy {
 }
 
 impl Clause for OrderBy {
-    fn compile(&self) -> Result<String> {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
         let mut buffer = Buffer::new();
         for expression in &self.0 {
-            buffer.push(try!(expression.compile()));
+            buffer.push(try!(expression.compile_with(context)));
         }
         Ok(format!("ORDER BY {}", buffer.join(", ")))
     }
@@ -73,14 +73,19 @@ impl<'l> Orderable for &'l str {
 }
 
 impl<T: Expression> Expression for (T, Option<Order>) {
-    fn compile(&self) -> Result<String> {
-        let main = try!(self.0.compile());
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let main = try!(self.0.compile_with(context));
         Ok(match self.1 {
             Some(Order::Ascending) => format!("{} ASC", main),
             Some(Order::Descending) => format!("{} DESC", main),
             _ => main,
         })
     }
+
+    #[inline]
+    fn is_column(&self) -> bool {
+        self.0.is_column()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,46 @@
+//! The `RETURNING` clause.
+
+use std::rc::Rc;
+
+use grammar::{Buffer, Clause, Context, Expression};
+use {Dialect, Result};
+
+/// A `RETURNING` clause.
+#[derive(Clone, Debug, Default)]
+pub struct Returning(Vec<Rc<Expression>>);
+
+impl Returning {
+    #[doc(hidden)]
+    pub fn append<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        self.0.push(Rc::new(expression));
+        self
+    }
+}
+
+impl Clause for Returning {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        if context.dialect() == Dialect::MySQL {
+            raise!("expected no RETURNING in MySQL");
+        }
+        let mut buffer = Buffer::new();
+        for expression in &self.0 {
+            buffer.push(try!(expression.compile_with(context)));
+        }
+        Ok(format!("RETURNING {}", buffer.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Clause, Context};
+    use prelude::*;
+
+    #[test]
+    fn append() {
+        let clause = super::Returning::default().append(column("foo")).append("bar");
+        assert_eq!(clause.compile().unwrap(), "RETURNING `foo`, bar");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(clause.compile_with(&mut context).is_err());
+    }
+}
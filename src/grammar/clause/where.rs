@@ -1,7 +1,7 @@
 //! The `WHERE` clause.
 
 use Result;
-use grammar::{Buffer, Clause, Condition};
+use grammar::{Buffer, Clause, Condition, Context};
 
 /// A `WHERE` clause.
 #[derive(Debug, Default)]
@@ -16,10 +16,10 @@ impl Where {
 }
 
 impl Clause for Where {
-    fn compile(&self) -> Result<String> {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
         let mut buffer = Buffer::new();
         for condition in &self.0 {
-            buffer.push(try!(condition.compile()));
+            buffer.push(try!(condition.compile_with(context)));
         }
         Ok(format!("WHERE {}", buffer.join(" AND ")))
     }
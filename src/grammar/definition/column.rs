@@ -1,4 +1,6 @@
-use grammar::{Buffer, Definition, Expression};
+use std::rc::Rc;
+
+use grammar::{Buffer, Condition, Context, Definition, Expression, is_name};
 use {Result, Typable, Type};
 
 /// A column definition.
@@ -7,6 +9,8 @@ pub struct Column {
     name: Option<String>,
     kind: Option<Type>,
     not_null: Option<()>,
+    collate: Option<String>,
+    check: Option<Rc<Condition>>,
 }
 
 impl Column {
@@ -33,28 +37,50 @@ impl Column {
         self.not_null = Some(());
         self
     }
+
+    /// Set the collation.
+    pub fn collate<T: ToString>(mut self, name: T) -> Self {
+        self.collate = Some(name.to_string());
+        self
+    }
+
+    /// Set a check.
+    pub fn check<T>(mut self, condition: T) -> Self where T: Condition + 'static {
+        self.check = Some(Rc::new(condition));
+        self
+    }
 }
 
 impl Definition for Column {
-    fn compile(&self) -> Result<String> {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
         let mut buffer = Buffer::new();
-        buffer.push(format!("`{}`", some!(self.name)));
-        buffer.push(match some!(self.kind) {
-            &Type::Binary => "BLOB",
-            &Type::Float => "REAL",
-            &Type::Integer => "INTEGER",
-            &Type::String => "TEXT",
-        });
+        buffer.push(context.quote(some!(self.name)));
+        buffer.push(context.kind(*some!(self.kind), false));
         if let Some(_) = self.not_null {
             buffer.push("NOT NULL");
         }
+        if let Some(ref name) = self.collate {
+            if !is_name(name) {
+                raise!(format!("expected a valid collation name, found “{}”", name));
+            }
+            buffer.push(format!("COLLATE {}", context.quote(name)));
+        }
+        if let Some(ref condition) = self.check {
+            let condition = try!(context.unbound(|context| condition.compile_with(context)));
+            buffer.push(format!("CHECK ({})", condition));
+        }
         Ok(buffer.join(" "))
     }
 }
 
 impl Expression for Column {
-    fn compile(&self) -> Result<String> {
-        Ok(format!("`{}`", some!(self.name)))
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        Ok(context.quote(some!(self.name)))
+    }
+
+    #[inline]
+    fn is_column(&self) -> bool {
+        true
     }
 }
 
@@ -78,7 +104,7 @@ impl<'l> Typable for &'l str {
 
 #[cfg(test)]
 mod tests {
-    use grammar::Definition;
+    use grammar::{Context, Definition};
     use prelude::*;
 
     #[test]
@@ -86,4 +112,37 @@ mod tests {
         let column = column("foo").kind(Type::Float).not_null();
         assert_eq!(Definition::compile(&column).unwrap(), "`foo` REAL NOT NULL");
     }
+
+    #[test]
+    fn kind() {
+        let definition = column("foo").binary();
+        assert_eq!(Definition::compile(&definition).unwrap(), "`foo` BLOB");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(Definition::compile_with(&definition, &mut context).unwrap(),
+                   "\"foo\" BYTEA");
+
+        let definition = column("foo").integer();
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(Definition::compile_with(&definition, &mut context).unwrap(), "`foo` BIGINT");
+    }
+
+    #[test]
+    fn collate() {
+        let definition = column("foo").string().collate("NOCASE");
+        assert_eq!(Definition::compile(&definition).unwrap(), "`foo` TEXT COLLATE `NOCASE`");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(Definition::compile_with(&definition, &mut context).unwrap(),
+                   "\"foo\" TEXT COLLATE \"NOCASE\"");
+
+        let definition = column("foo").string().collate("NO CASE");
+        assert!(Definition::compile(&definition).is_err());
+    }
+
+    #[test]
+    fn check() {
+        let column = column("foo").integer().check(column("foo").greater_than(0));
+        assert_eq!(Definition::compile(&column).unwrap(), "`foo` INTEGER CHECK (`foo` > 0)");
+    }
 }
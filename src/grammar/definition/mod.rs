@@ -1,5 +1,8 @@
 //! Definitions.
 
 mod column;
+mod window;
 
 pub use self::column::Column;
+pub use self::window::Bound;
+pub use self::window::Window;
@@ -0,0 +1,171 @@
+use grammar::clause::OrderBy;
+use grammar::{Buffer, Clause, Context, Definition, Expression};
+use {Dialect, Result};
+
+/// A window definition.
+#[derive(Debug, Default)]
+pub struct Window {
+    partition_by: Option<Vec<Box<Expression>>>,
+    order_by: Option<OrderBy>,
+    frame: Option<(Unit, Bound, Bound)>,
+}
+
+/// A frame bound.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Bound {
+    /// The start of the partition.
+    UnboundedPreceding,
+    /// A number of rows, values, or groups before the current row.
+    Preceding(usize),
+    /// The current row.
+    CurrentRow,
+    /// A number of rows, values, or groups after the current row.
+    Following(usize),
+    /// The end of the partition.
+    UnboundedFollowing,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Unit {
+    Groups,
+    Range,
+    Rows,
+}
+
+impl Window {
+    /// Create a window definition.
+    #[inline]
+    pub fn new() -> Self {
+        Window::default()
+    }
+
+    /// Add a partitioning expression.
+    pub fn partition_by<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        push!(self.partition_by, Box::new(expression));
+        self
+    }
+
+    /// Add an order.
+    pub fn order_by<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        self.order_by = Some(match self.order_by.take() {
+            Some(order_by) => order_by.append(expression),
+            _ => OrderBy::default().append(expression),
+        });
+        self
+    }
+
+    /// Set a frame counted in rows.
+    pub fn rows(mut self, start: Bound, end: Bound) -> Self {
+        self.frame = Some((Unit::Rows, start, end));
+        self
+    }
+
+    /// Set a frame counted in values of the ordering expression.
+    pub fn range(mut self, start: Bound, end: Bound) -> Self {
+        self.frame = Some((Unit::Range, start, end));
+        self
+    }
+
+    /// Set a frame counted in groups of peer rows.
+    pub fn groups(mut self, start: Bound, end: Bound) -> Self {
+        self.frame = Some((Unit::Groups, start, end));
+        self
+    }
+}
+
+impl Definition for Window {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let mut buffer = Buffer::new();
+        if let Some(ref expressions) = self.partition_by {
+            buffer.push({
+                let mut buffer = Buffer::new();
+                for expression in expressions {
+                    buffer.push(try!(expression.compile_with(context)));
+                }
+                format!("PARTITION BY {}", buffer.join(", "))
+            });
+        }
+        if let Some(ref clause) = self.order_by {
+            buffer.push(try!(clause.compile_with(context)));
+        }
+        if let Some((unit, start, end)) = self.frame {
+            buffer.push(match unit {
+                Unit::Groups => {
+                    if context.dialect() == Dialect::MySQL {
+                        raise!("expected no GROUPS frames in MySQL");
+                    }
+                    "GROUPS"
+                },
+                Unit::Range => "RANGE",
+                Unit::Rows => "ROWS",
+            });
+            if start == Bound::UnboundedFollowing || end == Bound::UnboundedPreceding {
+                raise!("expected a frame to start before its end is unbounded");
+            }
+            if start.rank() > end.rank() {
+                raise!(format!("expected a frame to end after its start, found {:?} before {:?}",
+                               end, start));
+            }
+            buffer.push(format!("BETWEEN {} AND {}", start.compile(), end.compile()));
+        }
+        Ok(buffer.join(" "))
+    }
+}
+
+impl Bound {
+    fn compile(&self) -> String {
+        match *self {
+            Bound::UnboundedPreceding => "UNBOUNDED PRECEDING".to_string(),
+            Bound::Preceding(count) => format!("{} PRECEDING", count),
+            Bound::CurrentRow => "CURRENT ROW".to_string(),
+            Bound::Following(count) => format!("{} FOLLOWING", count),
+            Bound::UnboundedFollowing => "UNBOUNDED FOLLOWING".to_string(),
+        }
+    }
+
+    fn rank(&self) -> usize {
+        match *self {
+            Bound::UnboundedPreceding => 0,
+            Bound::Preceding(_) => 1,
+            Bound::CurrentRow => 2,
+            Bound::Following(_) => 3,
+            Bound::UnboundedFollowing => 4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Context, Definition};
+    use prelude::*;
+
+    #[test]
+    fn frame() {
+        let definition = window().partition_by(column("foo"))
+                                 .order_by(column("bar").descend())
+                                 .rows(Bound::Preceding(2), Bound::CurrentRow);
+
+        assert_eq!(definition.compile().unwrap(),
+                   "PARTITION BY `foo` ORDER BY `bar` DESC \
+                    ROWS BETWEEN 2 PRECEDING AND CURRENT ROW");
+
+        let definition = window().groups(Bound::UnboundedPreceding, Bound::UnboundedFollowing);
+        assert_eq!(definition.compile().unwrap(),
+                   "GROUPS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(definition.compile_with(&mut context).is_err());
+
+        let definition = window().range(Bound::UnboundedFollowing, Bound::CurrentRow);
+        assert!(definition.compile().is_err());
+
+        let definition = window().rows(Bound::CurrentRow, Bound::Preceding(1));
+        assert!(definition.compile().is_err());
+
+        let definition = window().rows(Bound::Following(1), Bound::Preceding(2));
+        assert!(definition.compile().is_err());
+
+        let definition = window().rows(Bound::CurrentRow, Bound::CurrentRow);
+        assert_eq!(definition.compile().unwrap(), "ROWS BETWEEN CURRENT ROW AND CURRENT ROW");
+    }
+}
@@ -0,0 +1,109 @@
+//! The `CASE` expression.
+
+use Result;
+use grammar::{Buffer, Condition, Context, Expression};
+
+/// A `CASE` expression.
+#[derive(Debug, Default)]
+pub struct Case {
+    operand: Option<Box<Expression>>,
+    arms: Option<Vec<(Arm, Box<Expression>)>>,
+    otherwise: Option<Box<Expression>>,
+}
+
+#[derive(Debug)]
+enum Arm {
+    Condition(Box<Condition>),
+    Value(Box<Expression>),
+}
+
+impl Case {
+    /// Create a `CASE` expression.
+    #[inline]
+    pub fn new() -> Self {
+        Case::default()
+    }
+
+    /// Set the operand whose value the arms are compared with.
+    pub fn operand<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        self.operand = Some(Box::new(expression));
+        self
+    }
+
+    /// Add an arm taken if a condition holds.
+    pub fn when<T, U>(mut self, condition: T, result: U) -> Self
+        where T: Condition + 'static, U: Expression + 'static
+    {
+        push!(self.arms, (Arm::Condition(Box::new(condition)), Box::new(result)));
+        self
+    }
+
+    /// Add an arm taken if the operand is equal to a value.
+    pub fn when_value<T, U>(mut self, value: T, result: U) -> Self
+        where T: Expression + 'static, U: Expression + 'static
+    {
+        push!(self.arms, (Arm::Value(Box::new(value)), Box::new(result)));
+        self
+    }
+
+    /// Set the result for when no arm is taken.
+    pub fn otherwise<T>(mut self, result: T) -> Self where T: Expression + 'static {
+        self.otherwise = Some(Box::new(result));
+        self
+    }
+}
+
+impl Expression for Case {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let mut buffer = Buffer::new();
+        buffer.push("CASE");
+        if let Some(ref operand) = self.operand {
+            buffer.push(try!(operand.compile_with(context)));
+        }
+        for &(ref arm, ref result) in some!(self.arms) {
+            buffer.push("WHEN");
+            buffer.push(match (arm, &self.operand) {
+                (&Arm::Condition(ref condition), &None) => try!(condition.compile_with(context)),
+                (&Arm::Value(ref value), &Some(_)) => try!(value.compile_with(context)),
+                (&Arm::Condition(_), _) => raise!("expected no conditions with an operand"),
+                (&Arm::Value(_), _) => raise!("expected values only with an operand"),
+            });
+            buffer.push("THEN");
+            buffer.push(try!(result.compile_with(context)));
+        }
+        if let Some(ref result) = self.otherwise {
+            buffer.push("ELSE");
+            buffer.push(try!(result.compile_with(context)));
+        }
+        buffer.push("END");
+        Ok(buffer.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Expression;
+    use prelude::*;
+
+    #[test]
+    fn searched() {
+        let expression = case().when(column("foo").equal(1), value("bar"))
+                                .when(column("foo").greater_than(1), column("baz") + 1)
+                                .otherwise(value("qux"));
+
+        assert_eq!(expression.compile().unwrap(),
+                   "CASE WHEN `foo` = 1 THEN 'bar' WHEN `foo` > 1 THEN `baz` + 1 ELSE 'qux' END");
+    }
+
+    #[test]
+    fn simple() {
+        let expression = case_of(column("foo")).when_value(1, value("bar"))
+                                               .when_value(2, value("baz"));
+
+        assert_eq!(expression.compile().unwrap(),
+                   "CASE `foo` WHEN 1 THEN 'bar' WHEN 2 THEN 'baz' END");
+
+        let expression = case_of(column("foo")).when(column("foo").equal(1), value("bar"));
+        assert!(expression.compile().is_err());
+    }
+}
@@ -0,0 +1,311 @@
+//! Functions.
+
+use grammar::definition::Column;
+use grammar::expression::Value;
+use grammar::{Buffer, Context, Expression, is_name};
+use {Dialect, Result, Type};
+
+/// A function call.
+#[derive(Debug)]
+pub struct Function {
+    name: Name,
+    arguments: Vec<Box<Expression>>,
+}
+
+/// A `CAST` expression.
+#[derive(Debug)]
+pub struct Cast(Box<Expression>, Type);
+
+#[derive(Debug)]
+enum Name {
+    Abs,
+    Bm25,
+    Coalesce,
+    CurrentDate,
+    CurrentTime,
+    CurrentTimestamp,
+    Custom(String),
+    Date,
+    Highlight,
+    Length,
+    Lower,
+    NullIf,
+    Round,
+    Snippet,
+    Substr,
+    Upper,
+}
+
+impl Function {
+    /// Create a call to a user-defined function.
+    #[inline]
+    pub fn new<T: ToString>(name: T) -> Self {
+        Function::named(Name::Custom(name.to_string()))
+    }
+
+    fn named(name: Name) -> Self {
+        Function { name: name, arguments: vec![] }
+    }
+
+    /// Add an argument.
+    pub fn argument<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        self.arguments.push(Box::new(expression));
+        self
+    }
+}
+
+impl Expression for Function {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let dialect = context.dialect();
+        let (name, count) = match self.name {
+            Name::Bm25 | Name::Highlight | Name::Snippet if dialect != Dialect::SQLite => {
+                raise!("expected full-text functions only in SQLite");
+            },
+            Name::Abs => ("ABS", (1, 1)),
+            Name::Bm25 => ("bm25", (1, !0)),
+            Name::Coalesce => ("COALESCE", (2, !0)),
+            Name::CurrentDate => ("CURRENT_DATE", (0, 0)),
+            Name::CurrentTime => ("CURRENT_TIME", (0, 0)),
+            Name::CurrentTimestamp => ("CURRENT_TIMESTAMP", (0, 0)),
+            Name::Custom(ref name) => {
+                if !is_name(name) {
+                    raise!(format!("expected a valid function name, found “{}”", name));
+                }
+                (&name[..], (0, !0))
+            },
+            Name::Date if dialect == Dialect::MySQL => ("DATE", (1, 1)),
+            Name::Date => ("date", (1, 1)),
+            Name::Highlight => ("highlight", (4, 4)),
+            Name::Length if dialect == Dialect::MySQL => ("CHAR_LENGTH", (1, 1)),
+            Name::Length => ("LENGTH", (1, 1)),
+            Name::Lower => ("LOWER", (1, 1)),
+            Name::NullIf => ("NULLIF", (2, 2)),
+            Name::Round => ("ROUND", (1, 2)),
+            Name::Snippet => ("snippet", (6, 6)),
+            Name::Substr => ("SUBSTR", (2, 3)),
+            Name::Upper => ("UPPER", (1, 1)),
+        };
+        if self.arguments.len() < count.0 || self.arguments.len() > count.1 {
+            raise!(format!("expected a valid number of arguments for {}, found {}",
+                           name, self.arguments.len()));
+        }
+        match self.name {
+            Name::CurrentDate | Name::CurrentTime | Name::CurrentTimestamp => {
+                return Ok(name.to_string());
+            },
+            Name::Date if dialect == Dialect::PostgreSQL => {
+                let argument = try!(self.arguments[0].compile_with(context));
+                return Ok(format!("CAST({} AS DATE)", argument));
+            },
+            _ => {},
+        }
+        let mut buffer = Buffer::new();
+        for argument in &self.arguments {
+            buffer.push(try!(argument.compile_with(context)));
+        }
+        Ok(format!("{}({})", name, buffer.join(", ")))
+    }
+}
+
+impl Cast {
+    /// Create a `CAST` expression.
+    #[inline]
+    pub fn new<T>(expression: T, kind: Type) -> Self where T: Expression + 'static {
+        Cast(Box::new(expression), kind)
+    }
+}
+
+impl Expression for Cast {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let kind = context.kind(self.1, true);
+        Ok(format!("CAST({} AS {})", try!(self.0.compile_with(context)), kind))
+    }
+}
+
+macro_rules! implement(
+    ($($(#[$attribute:meta])* $function:ident => $name:ident,)*) => (
+        $(
+            $(#[$attribute])*
+            #[inline]
+            pub fn $function<T>(expression: T) -> Function where T: Expression + 'static {
+                Function::named(Name::$name).argument(expression)
+            }
+        )*
+    );
+);
+
+implement! {
+    /// Create an `ABS` function call.
+    abs => Abs,
+    /// Create a function call taking the date part of a timestamp.
+    date => Date,
+    /// Create a `LENGTH` function call counting characters.
+    length => Length,
+    /// Create a `LOWER` function call.
+    lower => Lower,
+    /// Create a `ROUND` function call, which takes the number of digits as
+    /// an optional second argument.
+    round => Round,
+    /// Create an `UPPER` function call.
+    upper => Upper,
+}
+
+/// Create a `COALESCE` function call, which takes the rest of the arguments
+/// via `argument`.
+#[inline]
+pub fn coalesce<T>(expression: T) -> Function where T: Expression + 'static {
+    Function::named(Name::Coalesce).argument(expression)
+}
+
+/// Create a `NULLIF` function call.
+#[inline]
+pub fn nullif<T, U>(first: T, second: U) -> Function
+    where T: Expression + 'static, U: Expression + 'static
+{
+    Function::named(Name::NullIf).argument(first).argument(second)
+}
+
+/// Create a `SUBSTR` function call, which takes the length as an optional
+/// third argument.
+#[inline]
+pub fn substr<T, U>(expression: T, start: U) -> Function
+    where T: Expression + 'static, U: Expression + 'static
+{
+    Function::named(Name::Substr).argument(expression).argument(start)
+}
+
+/// Create a `ROW_NUMBER` function call.
+#[inline]
+pub fn row_number() -> Function {
+    Function::new("ROW_NUMBER")
+}
+
+/// Create a `RANK` function call.
+#[inline]
+pub fn rank() -> Function {
+    Function::new("RANK")
+}
+
+/// Create a `DENSE_RANK` function call.
+#[inline]
+pub fn dense_rank() -> Function {
+    Function::new("DENSE_RANK")
+}
+
+/// Create a `bm25` function call ranking full-text matches of SQLite.
+#[inline]
+pub fn bm25<T: ToString>(table: T) -> Function {
+    Function::named(Name::Bm25).argument(Column::new(table))
+}
+
+/// Create a `highlight` function call marking full-text matches of SQLite.
+#[inline]
+pub fn highlight<T, U, V>(table: T, column: usize, open: U, close: V) -> Function
+    where T: ToString, U: Into<Value>, V: Into<Value>
+{
+    Function::named(Name::Highlight).argument(Column::new(table))
+                                    .argument(Value::Integer(column as i64))
+                                    .argument(open.into())
+                                    .argument(close.into())
+}
+
+/// Create a `snippet` function call excerpting full-text matches of SQLite.
+#[inline]
+pub fn snippet<T, U, V, W>(table: T, column: isize, open: U, close: V, ellipsis: W,
+                           tokens: usize) -> Function
+    where T: ToString, U: Into<Value>, V: Into<Value>, W: Into<Value>
+{
+    Function::named(Name::Snippet).argument(Column::new(table))
+                                  .argument(Value::Integer(column as i64))
+                                  .argument(open.into())
+                                  .argument(close.into())
+                                  .argument(ellipsis.into())
+                                  .argument(Value::Integer(tokens as i64))
+}
+
+/// Create a `CURRENT_DATE` expression.
+#[inline]
+pub fn current_date() -> Function {
+    Function::named(Name::CurrentDate)
+}
+
+/// Create a `CURRENT_TIME` expression.
+#[inline]
+pub fn current_time() -> Function {
+    Function::named(Name::CurrentTime)
+}
+
+/// Create a `CURRENT_TIMESTAMP` expression.
+#[inline]
+pub fn current_timestamp() -> Function {
+    Function::named(Name::CurrentTimestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Context, Expression};
+    use prelude::*;
+
+    #[test]
+    fn conversion() {
+        let expression = cast(column("foo"), Type::Integer);
+        assert_eq!(expression.compile().unwrap(), "CAST(`foo` AS INTEGER)");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(expression.compile_with(&mut context).unwrap(), "CAST(`foo` AS SIGNED)");
+    }
+
+    #[test]
+    fn scalar() {
+        let expression = coalesce(column("foo")).argument(nullif(column("bar"), value("")));
+        assert_eq!(expression.compile().unwrap(), "COALESCE(`foo`, NULLIF(`bar`, ''))");
+
+        let expression = upper(substr(column("foo"), 1).argument(2));
+        assert_eq!(expression.compile().unwrap(), "UPPER(SUBSTR(`foo`, 1, 2))");
+
+        let expression = length(column("foo"));
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(expression.compile_with(&mut context).unwrap(), "CHAR_LENGTH(`foo`)");
+
+        assert!(coalesce(column("foo")).compile().is_err());
+    }
+
+    #[test]
+    fn date_time() {
+        assert_eq!(current_timestamp().compile().unwrap(), "CURRENT_TIMESTAMP");
+        assert!(current_timestamp().argument(3).compile().is_err());
+
+        let expression = date(column("foo"));
+        assert_eq!(expression.compile().unwrap(), "date(`foo`)");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(expression.compile_with(&mut context).unwrap(), "DATE(`foo`)");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(expression.compile_with(&mut context).unwrap(), "CAST(\"foo\" AS DATE)");
+    }
+
+    #[test]
+    fn search() {
+        assert_eq!(bm25("foo").compile().unwrap(), "bm25(`foo`)");
+
+        let expression = highlight("foo", 1, "<b>", "</b>");
+        assert_eq!(expression.compile().unwrap(), "highlight(`foo`, 1, '<b>', '</b>')");
+
+        let expression = snippet("foo", -1, "<b>", "</b>", "...", 16);
+        assert_eq!(expression.compile().unwrap(),
+                   "snippet(`foo`, -1, '<b>', '</b>', '...', 16)");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert!(bm25("foo").compile_with(&mut context).is_err());
+        assert!(expression.compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn custom() {
+        let expression = function("foo").argument(column("bar")).argument(42);
+        assert_eq!(expression.compile().unwrap(), "foo(`bar`, 42)");
+
+        assert!(function("foo()").compile().is_err());
+    }
+}
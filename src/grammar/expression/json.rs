@@ -0,0 +1,289 @@
+//! JSON expressions.
+
+use grammar::definition::Column;
+use grammar::expression::operator::OTHER;
+use grammar::expression::{Function, Value};
+use grammar::{Buffer, Context, Expression};
+use {Dialect, Result};
+
+/// A JSON expression.
+#[derive(Debug)]
+pub struct Json(Box<Expression>, Kind);
+
+/// A JSON array.
+#[derive(Debug, Default)]
+pub struct JsonArray(Vec<Box<Expression>>);
+
+/// A JSON object.
+#[derive(Debug, Default)]
+pub struct JsonObject(Vec<(String, Box<Expression>)>);
+
+#[derive(Debug)]
+enum Kind {
+    Each,
+    Extract(String),
+    Get(Value),
+    GetText(Value),
+}
+
+/// An object that can be navigated as a JSON document.
+pub trait Extractable where Self: Sized {
+    /// Extract the value at a path, such as `$.foo[0]`.
+    fn json_extract<T: ToString>(self, T) -> Json;
+
+    /// Get the value of a field or an element as JSON.
+    fn json_get<T: Into<Value>>(self, T) -> Json;
+
+    /// Get the value of a field or an element as text.
+    fn json_get_text<T: Into<Value>>(self, T) -> Json;
+}
+
+impl Json {
+    /// Create a table-valued source iterating over a JSON document.
+    ///
+    /// In PostgreSQL, only the fields of an object can be iterated over.
+    #[inline]
+    pub fn each<T>(expression: T) -> Self where T: Expression + 'static {
+        Json(Box::new(expression), Kind::Each)
+    }
+}
+
+impl Expression for Json {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let dialect = context.dialect();
+        let subject = try!(self.0.compile_with(context));
+        Ok(match (dialect, &self.1) {
+            (Dialect::MySQL, &Kind::Each) => raise!("expected no JSON iteration in MySQL"),
+            (Dialect::PostgreSQL, &Kind::Each) => format!("jsonb_each({})", subject),
+            (Dialect::SQLite, &Kind::Each) => format!("json_each({})", subject),
+            (_, &Kind::Extract(ref path)) => {
+                let path = try!(context.string(path));
+                match dialect {
+                    Dialect::MySQL => format!("JSON_EXTRACT({}, {})", subject, path),
+                    Dialect::PostgreSQL => format!("jsonb_path_query_first({}, {})", subject, path),
+                    Dialect::SQLite => format!("json_extract({}, {})", subject, path),
+                }
+            },
+            (Dialect::MySQL, &Kind::Get(ref key)) => {
+                format!("JSON_EXTRACT({}, {})", subject, try!(path(key, context)))
+            },
+            (Dialect::MySQL, &Kind::GetText(ref key)) => {
+                format!("JSON_UNQUOTE(JSON_EXTRACT({}, {}))", subject, try!(path(key, context)))
+            },
+            (_, &Kind::Get(ref key)) => {
+                format!("{} -> {}", parenthesize(&*self.0, subject), try!(key_of(key, context)))
+            },
+            (_, &Kind::GetText(ref key)) => {
+                format!("{} ->> {}", parenthesize(&*self.0, subject), try!(key_of(key, context)))
+            },
+        })
+    }
+
+    #[inline]
+    fn precedence(&self) -> usize {
+        match self.1 {
+            Kind::Get(_) | Kind::GetText(_) => OTHER,
+            _ => ::std::usize::MAX,
+        }
+    }
+}
+
+impl JsonArray {
+    /// Create a JSON array.
+    #[inline]
+    pub fn new() -> Self {
+        JsonArray::default()
+    }
+
+    /// Add an element.
+    pub fn element<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        self.0.push(Box::new(expression));
+        self
+    }
+}
+
+impl Expression for JsonArray {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let name = match context.dialect() {
+            Dialect::MySQL => "JSON_ARRAY",
+            Dialect::PostgreSQL => "jsonb_build_array",
+            Dialect::SQLite => "json_array",
+        };
+        let mut buffer = Buffer::new();
+        for element in &self.0 {
+            buffer.push(try!(element.compile_with(context)));
+        }
+        Ok(format!("{}({})", name, buffer.join(", ")))
+    }
+}
+
+impl JsonObject {
+    /// Create a JSON object.
+    #[inline]
+    pub fn new() -> Self {
+        JsonObject::default()
+    }
+
+    /// Add a field.
+    pub fn field<T, U>(mut self, name: T, expression: U) -> Self
+        where T: ToString, U: Expression + 'static
+    {
+        self.0.push((name.to_string(), Box::new(expression)));
+        self
+    }
+}
+
+impl Expression for JsonObject {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let name = match context.dialect() {
+            Dialect::MySQL => "JSON_OBJECT",
+            Dialect::PostgreSQL => "jsonb_build_object",
+            Dialect::SQLite => "json_object",
+        };
+        let mut buffer = Buffer::new();
+        for &(ref field, ref expression) in &self.0 {
+            buffer.push(try!(context.string(field)));
+            buffer.push(try!(expression.compile_with(context)));
+        }
+        Ok(format!("{}({})", name, buffer.join(", ")))
+    }
+}
+
+fn key_of(key: &Value, context: &mut Context) -> Result<String> {
+    match key {
+        &Value::Integer(index) if index >= 0 => Ok(index.to_string()),
+        &Value::String(ref name) => context.string(name),
+        _ => raise!("expected a JSON key to be a string or a nonnegative integer"),
+    }
+}
+
+fn parenthesize(expression: &Expression, value: String) -> String {
+    if expression.precedence() == ::std::usize::MAX {
+        value
+    } else {
+        format!("({})", value)
+    }
+}
+
+fn path(key: &Value, context: &mut Context) -> Result<String> {
+    match key {
+        &Value::Integer(index) if index >= 0 => context.string(&format!("$[{}]", index)),
+        &Value::String(ref name) => {
+            let mut path = String::from("$.\"");
+            for character in name.chars() {
+                if character == '"' || character == '\\' {
+                    path.push('\\');
+                }
+                path.push(character);
+            }
+            path.push('"');
+            context.string(&path)
+        },
+        _ => raise!("expected a JSON key to be a string or a nonnegative integer"),
+    }
+}
+
+macro_rules! implement(
+    ($([$($lifetime:tt)*] $kind:ty, $subject:ident => $convert:expr),*) => (
+        $(
+            impl<$($lifetime)*> Extractable for $kind {
+                #[inline]
+                fn json_extract<T: ToString>(self, path: T) -> Json {
+                    let $subject = self;
+                    Json(Box::new($convert), Kind::Extract(path.to_string()))
+                }
+
+                #[inline]
+                fn json_get<T: Into<Value>>(self, key: T) -> Json {
+                    let $subject = self;
+                    Json(Box::new($convert), Kind::Get(key.into()))
+                }
+
+                #[inline]
+                fn json_get_text<T: Into<Value>>(self, key: T) -> Json {
+                    let $subject = self;
+                    Json(Box::new($convert), Kind::GetText(key.into()))
+                }
+            }
+        )*
+    );
+);
+
+implement!([] Column, subject => subject,
+           [] Function, subject => subject,
+           [] Json, subject => subject,
+           ['l] &'l str, subject => subject.to_string());
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Context, Expression};
+    use prelude::*;
+
+    #[test]
+    fn extract() {
+        let expression = column("foo").json_extract("$.bar[0]");
+        assert_eq!(expression.compile().unwrap(), "json_extract(`foo`, '$.bar[0]')");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(expression.compile_with(&mut context).unwrap(),
+                   "jsonb_path_query_first(\"foo\", '$.bar[0]')");
+    }
+
+    #[test]
+    fn get() {
+        let expression = column("foo").json_get("bar").json_get_text(0);
+        assert_eq!(expression.compile().unwrap(), "(`foo` -> 'bar') ->> 0");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(expression.compile_with(&mut context).unwrap(),
+                   "JSON_UNQUOTE(JSON_EXTRACT(JSON_EXTRACT(`foo`, '$.\"bar\"'), '$[0]'))");
+
+        let expression = column("foo").json_get(true);
+        assert!(expression.compile().is_err());
+
+        let expression = column("foo").json_get(-1);
+        assert!(expression.compile().is_err());
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert!(expression.compile_with(&mut context).is_err());
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(expression.compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn from_string() {
+        assert_eq!("foo".json_get("bar").compile().unwrap(), "foo -> 'bar'");
+        assert_eq!("foo".json_get_text(0).compile().unwrap(), "foo ->> 0");
+        assert_eq!("foo".json_extract("$.bar").compile().unwrap(), "json_extract(foo, '$.bar')");
+    }
+
+    #[test]
+    fn get_in_operation() {
+        let expression = column("foo").json_get_text("bar").concatenate("baz");
+        assert_eq!(expression.compile().unwrap(), "(`foo` ->> 'bar') || baz");
+    }
+
+    #[test]
+    fn builders() {
+        let expression = json_object().field("foo", 42)
+                                      .field("bar", json_array().element(column("baz"))
+                                                                .element(value("qux")));
+
+        assert_eq!(expression.compile().unwrap(),
+                   "json_object('foo', 42, 'bar', json_array(`baz`, 'qux'))");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(expression.compile_with(&mut context).unwrap(),
+                   "jsonb_build_object('foo', 42, 'bar', jsonb_build_array(\"baz\", 'qux'))");
+    }
+
+    #[test]
+    fn each() {
+        let expression = json_each(column("foo"));
+        assert_eq!(expression.compile().unwrap(), "json_each(`foo`)");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(expression.compile_with(&mut context).is_err());
+    }
+}
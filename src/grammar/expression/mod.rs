@@ -0,0 +1,27 @@
+//! Expressions.
+
+pub mod case;
+pub mod function;
+pub mod json;
+pub mod operator;
+pub mod over;
+pub mod placeholder;
+pub mod value;
+
+pub use self::case::Case;
+pub use self::function::Cast;
+pub use self::function::Function;
+pub use self::json::Extractable;
+pub use self::json::Json;
+pub use self::json::JsonArray;
+pub use self::json::JsonObject;
+pub use self::operator::Binary;
+pub use self::operator::Operable;
+pub use self::operator::Operator;
+pub use self::operator::Unary;
+pub use self::operator::UnaryOperator;
+pub use self::over::Over;
+pub use self::over::Windowable;
+pub use self::placeholder::Named;
+pub use self::placeholder::Placeholder;
+pub use self::value::Value;
@@ -0,0 +1,312 @@
+//! Operators.
+
+use std::ops;
+
+use grammar::definition::Column;
+use grammar::{Context, Expression};
+use {Dialect, Result};
+
+/// A binary operation.
+#[derive(Debug)]
+pub struct Binary(Box<Expression>, Operator, Box<Expression>);
+
+/// A unary operation.
+#[derive(Debug)]
+pub struct Unary(UnaryOperator, Box<Expression>);
+
+/// A binary operator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operator {
+    /// The addition.
+    Add,
+    /// The subtraction.
+    Subtract,
+    /// The multiplication.
+    Multiply,
+    /// The division.
+    Divide,
+    /// The remainder of a division.
+    Remainder,
+    /// The string concatenation.
+    Concatenate,
+    /// The bitwise conjunction.
+    BitAnd,
+    /// The bitwise disjunction.
+    BitOr,
+    /// The bitwise left shift.
+    ShiftLeft,
+    /// The bitwise right shift.
+    ShiftRight,
+}
+
+/// A unary operator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnaryOperator {
+    /// The negation.
+    Negate,
+    /// The bitwise negation.
+    BitNot,
+}
+
+/// An object that can be operated on.
+pub trait Operable where Self: Sized {
+    /// Apply a binary operator.
+    fn operate<T>(self, Operator, T) -> Binary where T: Expression + 'static;
+
+    /// Apply a unary operator.
+    fn operate_unary(self, UnaryOperator) -> Unary;
+
+    /// Add.
+    #[inline]
+    fn add<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::Add, value)
+    }
+
+    /// Subtract.
+    #[inline]
+    fn subtract<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::Subtract, value)
+    }
+
+    /// Multiply.
+    #[inline]
+    fn multiply<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::Multiply, value)
+    }
+
+    /// Divide.
+    #[inline]
+    fn divide<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::Divide, value)
+    }
+
+    /// Take the remainder of a division.
+    #[inline]
+    fn remainder<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::Remainder, value)
+    }
+
+    /// Concatenate.
+    #[inline]
+    fn concatenate<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::Concatenate, value)
+    }
+
+    /// Apply the bitwise conjunction.
+    #[inline]
+    fn bit_and<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::BitAnd, value)
+    }
+
+    /// Apply the bitwise disjunction.
+    #[inline]
+    fn bit_or<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::BitOr, value)
+    }
+
+    /// Shift bits to the left.
+    #[inline]
+    fn shift_left<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::ShiftLeft, value)
+    }
+
+    /// Shift bits to the right.
+    #[inline]
+    fn shift_right<T>(self, value: T) -> Binary where T: Expression + 'static {
+        self.operate(Operator::ShiftRight, value)
+    }
+
+    /// Negate.
+    #[inline]
+    fn negate(self) -> Unary {
+        self.operate_unary(UnaryOperator::Negate)
+    }
+
+    /// Apply the bitwise negation.
+    #[inline]
+    fn bit_not(self) -> Unary {
+        self.operate_unary(UnaryOperator::BitNot)
+    }
+}
+
+// The precedence of concatenation and bitwise operators differs between
+// dialects, and such operations are therefore always parenthesized.
+pub(crate) const OTHER: usize = 0;
+const ADDITIVE: usize = 1;
+const MULTIPLICATIVE: usize = 2;
+const UNARY: usize = 3;
+
+impl Operator {
+    fn precedence(&self) -> usize {
+        match *self {
+            Operator::Add | Operator::Subtract => ADDITIVE,
+            Operator::Multiply | Operator::Divide | Operator::Remainder => MULTIPLICATIVE,
+            _ => OTHER,
+        }
+    }
+}
+
+fn operand(expression: &Expression, parent: usize, right: bool,
+           context: &mut Context) -> Result<String> {
+
+    let value = try!(expression.compile_with(context));
+    let child = expression.precedence();
+    if child < UNARY && (parent == OTHER || child == OTHER || child < parent ||
+                         child == parent && right) {
+        Ok(format!("({})", value))
+    } else {
+        Ok(value)
+    }
+}
+
+impl Expression for Binary {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        if self.1 == Operator::Concatenate && context.dialect() == Dialect::MySQL {
+            let left = try!(self.0.compile_with(context));
+            let right = try!(self.2.compile_with(context));
+            return Ok(format!("CONCAT({}, {})", left, right));
+        }
+        let precedence = self.precedence();
+        let left = try!(operand(&*self.0, precedence, false, context));
+        let right = try!(operand(&*self.2, precedence, true, context));
+        let operator = match self.1 {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Remainder => "%",
+            Operator::Concatenate => "||",
+            Operator::BitAnd => "&",
+            Operator::BitOr => "|",
+            Operator::ShiftLeft => "<<",
+            Operator::ShiftRight => ">>",
+        };
+        Ok(format!("{} {} {}", left, operator, right))
+    }
+
+    #[inline]
+    fn precedence(&self) -> usize {
+        self.1.precedence()
+    }
+}
+
+impl Expression for Unary {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let mut value = try!(self.1.compile_with(context));
+        if self.1.precedence() < UNARY || value.starts_with('-') || value.starts_with('~') {
+            value = format!("({})", value);
+        }
+        Ok(match self.0 {
+            UnaryOperator::Negate => format!("-{}", value),
+            UnaryOperator::BitNot => format!("~{}", value),
+        })
+    }
+
+    #[inline]
+    fn precedence(&self) -> usize {
+        UNARY
+    }
+}
+
+impl<T> Operable for T where T: Expression + 'static {
+    #[inline]
+    fn operate<U>(self, operator: Operator, value: U) -> Binary where U: Expression + 'static {
+        Binary(Box::new(self), operator, Box::new(value))
+    }
+
+    #[inline]
+    fn operate_unary(self, operator: UnaryOperator) -> Unary {
+        Unary(operator, Box::new(self))
+    }
+}
+
+macro_rules! implement(
+    ($kind:ty) => (
+        implement!($kind, Add, add, Add);
+        implement!($kind, Sub, sub, Subtract);
+        implement!($kind, Mul, mul, Multiply);
+        implement!($kind, Div, div, Divide);
+        implement!($kind, Rem, rem, Remainder);
+        implement!($kind, BitAnd, bitand, BitAnd);
+        implement!($kind, BitOr, bitor, BitOr);
+        implement!($kind, Shl, shl, ShiftLeft);
+        implement!($kind, Shr, shr, ShiftRight);
+
+        impl ops::Neg for $kind {
+            type Output = Unary;
+
+            #[inline]
+            fn neg(self) -> Unary {
+                self.operate_unary(UnaryOperator::Negate)
+            }
+        }
+
+        impl ops::Not for $kind {
+            type Output = Unary;
+
+            #[inline]
+            fn not(self) -> Unary {
+                self.operate_unary(UnaryOperator::BitNot)
+            }
+        }
+    );
+    ($kind:ty, $trait:ident, $method:ident, $operator:ident) => (
+        impl<T> ops::$trait<T> for $kind where T: Expression + 'static {
+            type Output = Binary;
+
+            #[inline]
+            fn $method(self, value: T) -> Binary {
+                self.operate(Operator::$operator, value)
+            }
+        }
+    );
+);
+
+implement!(Binary);
+implement!(Column);
+implement!(Unary);
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Condition, Context, Expression};
+    use prelude::*;
+
+    #[test]
+    fn precedence() {
+        let expression = (column("foo") + 1) * column("bar") - 2;
+        assert_eq!(expression.compile().unwrap(), "(`foo` + 1) * `bar` - 2");
+
+        let expression = column("foo") - (column("bar") - column("baz"));
+        assert_eq!(expression.compile().unwrap(), "`foo` - (`bar` - `baz`)");
+
+        let expression = column("foo") * 2 + column("bar") / 3 % 4;
+        assert_eq!(expression.compile().unwrap(), "`foo` * 2 + `bar` / 3 % 4");
+    }
+
+    #[test]
+    fn other() {
+        let expression = (column("foo") & 1) | column("bar") << 2;
+        assert_eq!(expression.compile().unwrap(), "(`foo` & 1) | (`bar` << 2)");
+
+        let expression = column("foo").concatenate(value("-")).concatenate(column("bar"));
+        assert_eq!(expression.compile().unwrap(), "(`foo` || '-') || `bar`");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(expression.compile_with(&mut context).unwrap(),
+                   "CONCAT(CONCAT(`foo`, '-'), `bar`)");
+    }
+
+    #[test]
+    fn unary() {
+        assert_eq!((-(column("foo") + 1)).compile().unwrap(), "-(`foo` + 1)");
+        assert_eq!((-column("foo")).negate().compile().unwrap(), "-(-`foo`)");
+        assert_eq!((!column("foo")).compile().unwrap(), "~`foo`");
+        assert_eq!(value(-1).negate().compile().unwrap(), "-(-1)");
+    }
+
+    #[test]
+    fn condition() {
+        let condition = (column("foo") * 2).greater_than(column("bar") + 1);
+        assert_eq!(Condition::compile(&condition).unwrap(), "`foo` * 2 > `bar` + 1");
+    }
+}
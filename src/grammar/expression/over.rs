@@ -0,0 +1,67 @@
+//! The `OVER` expression.
+
+use Result;
+use grammar::definition::Window;
+use grammar::expression::Function;
+use grammar::{Context, Definition, Expression};
+
+/// A window function call.
+#[derive(Debug)]
+pub struct Over(Function, Frame);
+
+#[derive(Debug)]
+enum Frame {
+    Definition(Window),
+    Name(String),
+}
+
+/// An object that can be applied over a window.
+pub trait Windowable where Self: Sized {
+    /// Apply over a window.
+    fn over(self, Window) -> Over;
+
+    /// Apply over a named window.
+    fn over_named<T: ToString>(self, T) -> Over;
+}
+
+impl Expression for Over {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let function = try!(self.0.compile_with(context));
+        Ok(match self.1 {
+            Frame::Definition(ref window) => {
+                format!("{} OVER ({})", function, try!(window.compile_with(context)))
+            },
+            Frame::Name(ref name) => format!("{} OVER {}", function, context.quote(name)),
+        })
+    }
+}
+
+impl Windowable for Function {
+    #[inline]
+    fn over(self, window: Window) -> Over {
+        Over(self, Frame::Definition(window))
+    }
+
+    #[inline]
+    fn over_named<T: ToString>(self, name: T) -> Over {
+        Over(self, Frame::Name(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Expression;
+    use prelude::*;
+
+    #[test]
+    fn over() {
+        let expression = row_number().over(window().partition_by(column("foo"))
+                                                   .order_by(column("bar")));
+
+        assert_eq!(expression.compile().unwrap(),
+                   "ROW_NUMBER() OVER (PARTITION BY `foo` ORDER BY `bar`)");
+
+        let expression = function("sum").argument(column("foo")).over_named("bar");
+        assert_eq!(expression.compile().unwrap(), "sum(`foo`) OVER `bar`");
+    }
+}
@@ -0,0 +1,94 @@
+//! Placeholders.
+
+use Result;
+use grammar::{Context, Expression, is_name};
+
+/// A placeholder for a value bound at execution.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Placeholder;
+
+/// A named placeholder for a value bound at execution.
+#[derive(Clone, Debug)]
+pub struct Named(char, String);
+
+impl Named {
+    /// Create a named placeholder.
+    ///
+    /// The name can be prefixed with `:`, `@`, or `$`, which is `:` by
+    /// default.
+    pub fn new<T: ToString>(name: T) -> Self {
+        let name = name.to_string();
+        match name.chars().next() {
+            Some(prefix @ ':') | Some(prefix @ '@') | Some(prefix @ '$') => {
+                Named(prefix, name[1..].to_string())
+            },
+            _ => Named(':', name),
+        }
+    }
+}
+
+impl Expression for Named {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        if !is_name(&self.1) {
+            raise!(format!("expected a valid placeholder name, found “{}”", self.1));
+        }
+        context.named(self.0, &self.1)
+    }
+}
+
+impl Expression for Placeholder {
+    #[inline]
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        context.placeholder()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Context, Expression, Statement};
+    use prelude::*;
+
+    #[test]
+    fn prefix() {
+        assert_eq!(named("foo").compile().unwrap(), ":foo");
+        assert_eq!(named("@foo").compile().unwrap(), "@foo");
+        assert!(named("$").compile().is_err());
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(named("$foo").compile_with(&mut context).unwrap(), "?");
+        assert_eq!(context.names()["foo"], vec![1]);
+    }
+
+    #[test]
+    fn positional() {
+        let statement = select_from("foo").so_that(column("bar").equal(named("baz")))
+                                          .so_that(column("qux").equal(placeholder()))
+                                          .so_that(column("quux").equal(named("baz")));
+
+        let (statement, names) = statement.compile_positional(Dialect::SQLite).unwrap();
+        assert_eq!(statement, "SELECT * FROM `foo` WHERE `bar` = ? AND `qux` = ? AND `quux` = ?");
+        assert_eq!(names["baz"], vec![1, 3]);
+
+        let statement = select_from("foo").so_that(column("bar").equal(named("baz")))
+                                          .so_that(column("qux").equal(named("baz")));
+
+        let (statement, names) = statement.compile_positional(Dialect::PostgreSQL).unwrap();
+        assert_eq!(statement, "SELECT * FROM \"foo\" WHERE \"bar\" = $1 AND \"qux\" = $1");
+        assert_eq!(names["baz"], vec![1]);
+    }
+
+    #[test]
+    fn numbered() {
+        let query = select_from("qux").columns(&["bar", "baz"])
+                                      .so_that(column("bar").equal(named("a")))
+                                      .so_that(column("baz").equal(placeholder()))
+                                      .so_that(column("qux").equal(named("a")));
+        let statement = insert_into("foo").columns(&["bar", "baz"]).query(query);
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "INSERT INTO \"foo\" (\"bar\", \"baz\") SELECT \"bar\", \"baz\" FROM \"qux\" \
+                    WHERE \"bar\" = $1 AND \"baz\" = $2 AND \"qux\" = $1");
+        assert_eq!(context.names()["a"], vec![1]);
+    }
+}
@@ -0,0 +1,186 @@
+//! Values.
+
+use grammar::{Context, Expression};
+use {Dialect, Result, Type};
+
+/// A value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// Binary data.
+    Binary(Vec<u8>),
+    /// A Boolean value.
+    Boolean(bool),
+    /// A floating-point number.
+    Float(f64),
+    /// An integer.
+    Integer(i64),
+    /// A string.
+    String(String),
+    /// The null value.
+    Null,
+}
+
+impl Value {
+    /// Return the type if the value is not null.
+    pub fn kind(&self) -> Option<Type> {
+        match self {
+            &Value::Binary(_) => Some(Type::Binary),
+            &Value::Boolean(_) | &Value::Integer(_) => Some(Type::Integer),
+            &Value::Float(_) => Some(Type::Float),
+            &Value::String(_) => Some(Type::String),
+            &Value::Null => None,
+        }
+    }
+}
+
+impl Expression for Value {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        if let Some(parameter) = context.parameter(self) {
+            return Ok(parameter);
+        }
+        let dialect = context.dialect();
+        Ok(match self {
+            &Value::Binary(ref value) => {
+                let mut result = String::with_capacity(2 * value.len() + 3);
+                result.push_str(match dialect {
+                    Dialect::PostgreSQL => "'\\x",
+                    _ => "X'",
+                });
+                for byte in value {
+                    result.push_str(&format!("{:02X}", byte));
+                }
+                result.push('\'');
+                result
+            },
+            &Value::Boolean(value) => match (dialect, value) {
+                (Dialect::SQLite, true) => "1".to_string(),
+                (Dialect::SQLite, false) => "0".to_string(),
+                (_, true) => "TRUE".to_string(),
+                (_, false) => "FALSE".to_string(),
+            },
+            &Value::Float(value) => {
+                if !value.is_finite() {
+                    raise!(format!("expected a finite number, found {}", value));
+                }
+                format!("{:?}", value)
+            },
+            &Value::Integer(value) => value.to_string(),
+            &Value::String(ref value) => try!(context.string(value)),
+            &Value::Null => "NULL".to_string(),
+        })
+    }
+}
+
+macro_rules! implement(
+    ($kind:ty, $variant:ident) => (
+        impl From<$kind> for Value {
+            #[inline]
+            fn from(value: $kind) -> Self {
+                Value::$variant(value.into())
+            }
+        }
+
+        impl Expression for $kind {
+            #[inline]
+            fn compile_with(&self, context: &mut Context) -> Result<String> {
+                Value::from(*self).compile_with(context)
+            }
+        }
+    );
+);
+
+implement!(bool, Boolean);
+implement!(f32, Float);
+implement!(f64, Float);
+implement!(i8, Integer);
+implement!(i16, Integer);
+implement!(i32, Integer);
+implement!(i64, Integer);
+implement!(u8, Integer);
+implement!(u16, Integer);
+implement!(u32, Integer);
+
+impl<'l> From<&'l str> for Value {
+    #[inline]
+    fn from(value: &'l str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    #[inline]
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl<'l> From<&'l [u8]> for Value {
+    #[inline]
+    fn from(value: &'l [u8]) -> Self {
+        Value::Binary(value.to_vec())
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    #[inline]
+    fn from(value: Vec<u8>) -> Self {
+        Value::Binary(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    #[inline]
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            _ => Value::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Context, Expression};
+    use prelude::*;
+
+    macro_rules! compile(
+        ($value:expr) => (value($value).compile().unwrap());
+        ($value:expr, $dialect:expr) => (
+            value($value).compile_with(&mut Context::new($dialect)).unwrap()
+        );
+    );
+
+    #[test]
+    fn binary() {
+        assert_eq!(compile!(&[0x0Fu8, 0xA0][..]), "X'0FA0'");
+        assert_eq!(compile!(vec![0x0Fu8, 0xA0], Dialect::PostgreSQL), "'\\x0FA0'");
+    }
+
+    #[test]
+    fn boolean() {
+        assert_eq!(compile!(true), "1");
+        assert_eq!(compile!(false, Dialect::PostgreSQL), "FALSE");
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(compile!(42.0), "42.0");
+        assert!(value(::std::f64::NAN).compile().is_err());
+        assert!(value(::std::f64::INFINITY).compile().is_err());
+    }
+
+    #[test]
+    fn string() {
+        assert_eq!(compile!("foo 'bar'"), "'foo ''bar'''");
+        assert_eq!(compile!("foo\\bar", Dialect::MySQL), "'foo\\\\bar'");
+        assert_eq!(compile!(None::<&str>), "NULL");
+    }
+
+    #[test]
+    fn bind() {
+        let mut context = Context::default().bind();
+        assert_eq!(value("foo").compile_with(&mut context).unwrap(), "?");
+        assert_eq!(value(42).compile_with(&mut context).unwrap(), "?");
+        assert_eq!(context.parameters(), &[Value::String("foo".to_string()), Value::Integer(42)]);
+    }
+}
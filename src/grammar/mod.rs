@@ -1,45 +1,291 @@
 //! The grammar.
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 
-use Result;
+use grammar::expression::Value;
+use {Dialect, Result, Type};
 
 struct Buffer(Vec<String>);
 
+/// A compilation context.
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    dialect: Dialect,
+    parameters: Option<Vec<Value>>,
+    positional: Option<()>,
+    names: HashMap<String, Vec<usize>>,
+    count: usize,
+}
+
 /// A clause.
 pub trait Clause: Debug {
     /// Compile the clause.
-    fn compile(&self) -> Result<String>;
+    #[inline]
+    fn compile(&self) -> Result<String> {
+        self.compile_with(&mut Context::default())
+    }
+
+    /// Compile the clause in a context.
+    fn compile_with(&self, context: &mut Context) -> Result<String>;
 }
 
 /// A condition.
 pub trait Condition: Debug {
     /// Compile the condition.
-    fn compile(&self) -> Result<String>;
+    #[inline]
+    fn compile(&self) -> Result<String> {
+        self.compile_with(&mut Context::default())
+    }
+
+    /// Compile the condition in a context.
+    fn compile_with(&self, context: &mut Context) -> Result<String>;
 }
 
 /// A definition.
 pub trait Definition: Debug {
     /// Compile the definition.
-    fn compile(&self) -> Result<String>;
+    #[inline]
+    fn compile(&self) -> Result<String> {
+        self.compile_with(&mut Context::default())
+    }
+
+    /// Compile the definition in a context.
+    fn compile_with(&self, context: &mut Context) -> Result<String>;
 }
 
 /// An expression.
 pub trait Expression: Debug {
     /// Compile the expression.
-    fn compile(&self) -> Result<String>;
+    #[inline]
+    fn compile(&self) -> Result<String> {
+        self.compile_with(&mut Context::default())
+    }
+
+    /// Compile the expression in a context.
+    fn compile_with(&self, context: &mut Context) -> Result<String>;
+
+    #[doc(hidden)]
+    #[inline]
+    fn is_column(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn precedence(&self) -> usize {
+        ::std::usize::MAX
+    }
 }
 
 /// An operation.
 pub trait Operation: Debug {
     /// Compile the operation.
-    fn compile(&self) -> Result<String>;
+    #[inline]
+    fn compile(&self) -> Result<String> {
+        self.compile_with(&mut Context::default())
+    }
+
+    /// Compile the operation in a context.
+    fn compile_with(&self, context: &mut Context) -> Result<String>;
 }
 
 /// A statement.
 pub trait Statement: Debug {
     /// Compile the statement.
-    fn compile(&self) -> Result<String>;
+    #[inline]
+    fn compile(&self) -> Result<String> {
+        self.compile_with(&mut Context::default())
+    }
+
+    /// Compile the statement for a dialect with values bound as parameters.
+    fn compile_bound(&self, dialect: Dialect) -> Result<(String, Vec<Value>)> {
+        let mut context = Context::new(dialect).bind();
+        let statement = try!(self.compile_with(&mut context));
+        Ok((statement, context.take_parameters()))
+    }
+
+    /// Compile the statement for a dialect with named placeholders rewritten
+    /// to positional ones and return the positions of each name.
+    fn compile_positional(&self, dialect: Dialect)
+                          -> Result<(String, HashMap<String, Vec<usize>>)> {
+
+        let mut context = Context::new(dialect).positional();
+        let statement = try!(self.compile_with(&mut context));
+        Ok((statement, context.take_names()))
+    }
+
+    /// Compile the statement in a context.
+    fn compile_with(&self, context: &mut Context) -> Result<String>;
+}
+
+impl Context {
+    /// Create a context.
+    #[inline]
+    pub fn new(dialect: Dialect) -> Self {
+        Context { dialect: dialect, .. Context::default() }
+    }
+
+    /// Mark that values should be bound as parameters instead of being
+    /// embedded as literals.
+    pub fn bind(mut self) -> Self {
+        if self.parameters.is_none() {
+            self.parameters = Some(vec![]);
+        }
+        self
+    }
+
+    /// Mark that named placeholders should be rewritten to positional ones
+    /// even if the dialect supports named ones.
+    pub fn positional(mut self) -> Self {
+        self.positional = Some(());
+        self
+    }
+
+    /// Return the dialect.
+    #[inline]
+    pub fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    /// Return the bound parameters in the order of their appearance.
+    #[inline]
+    pub fn parameters(&self) -> &[Value] {
+        match self.parameters {
+            Some(ref parameters) => parameters,
+            _ => &[],
+        }
+    }
+
+    /// Take the bound parameters leaving none behind.
+    #[inline]
+    pub fn take_parameters(&mut self) -> Vec<Value> {
+        match self.parameters {
+            Some(ref mut parameters) => ::std::mem::replace(parameters, vec![]),
+            _ => vec![],
+        }
+    }
+
+    /// Return the positions, starting from one, of the named placeholders
+    /// that have been rewritten to positional ones.
+    ///
+    /// In PostgreSQL, all occurrences of a name share the same position.
+    #[inline]
+    pub fn names(&self) -> &HashMap<String, Vec<usize>> {
+        &self.names
+    }
+
+    /// Take the positions of the named placeholders leaving none behind.
+    #[inline]
+    pub fn take_names(&mut self) -> HashMap<String, Vec<usize>> {
+        ::std::mem::replace(&mut self.names, HashMap::new())
+    }
+
+    fn parameter(&mut self, value: &Value) -> Option<String> {
+        if self.parameters.is_none() {
+            return None;
+        }
+        if let Some(ref mut parameters) = self.parameters {
+            parameters.push(value.clone());
+        }
+        Some(self.position())
+    }
+
+    fn placeholder(&mut self) -> Result<String> {
+        if self.parameters.is_some() {
+            raise!("expected no placeholders without values when binding parameters");
+        }
+        Ok(self.position())
+    }
+
+    fn named(&mut self, prefix: char, name: &str) -> Result<String> {
+        if self.parameters.is_some() {
+            raise!("expected no placeholders without values when binding parameters");
+        }
+        if self.dialect == Dialect::SQLite && self.positional.is_none() {
+            return Ok(format!("{}{}", prefix, name));
+        }
+        if self.dialect == Dialect::PostgreSQL {
+            if let Some(positions) = self.names.get(name) {
+                return Ok(format!("${}", positions[0]));
+            }
+        }
+        let position = self.position();
+        self.names.entry(name.to_string()).or_insert_with(Vec::new).push(self.count);
+        Ok(position)
+    }
+
+    fn position(&mut self) -> String {
+        self.count += 1;
+        match self.dialect {
+            Dialect::PostgreSQL => format!("${}", self.count),
+            _ => "?".to_string(),
+        }
+    }
+
+    fn string(&self, value: &str) -> Result<String> {
+        let mut result = String::with_capacity(value.len() + 2);
+        result.push('\'');
+        for character in value.chars() {
+            match character {
+                '\'' => result.push('\''),
+                '\\' if self.dialect == Dialect::MySQL => result.push('\\'),
+                '\0' => raise!("expected no null characters in a string"),
+                _ => {},
+            }
+            result.push(character);
+        }
+        result.push('\'');
+        Ok(result)
+    }
+
+    // Parameters are not allowed in schema definitions, and values are
+    // therefore always embedded as literals there.
+    fn unbound<F>(&mut self, compile: F) -> Result<String>
+        where F: FnOnce(&mut Context) -> Result<String>
+    {
+        let parameters = self.parameters.take();
+        let result = compile(self);
+        self.parameters = parameters;
+        result
+    }
+
+    fn kind(&self, kind: Type, cast: bool) -> &'static str {
+        match (self.dialect, kind) {
+            (Dialect::MySQL, Type::Binary) if cast => "BINARY",
+            (Dialect::MySQL, Type::Binary) => "BLOB",
+            (Dialect::MySQL, Type::Float) => "DOUBLE",
+            (Dialect::MySQL, Type::Integer) if cast => "SIGNED",
+            (Dialect::MySQL, Type::Integer) => "BIGINT",
+            (Dialect::MySQL, Type::String) if cast => "CHAR",
+            (Dialect::MySQL, Type::String) => "TEXT",
+            (Dialect::PostgreSQL, Type::Binary) => "BYTEA",
+            (Dialect::PostgreSQL, Type::Float) => "DOUBLE PRECISION",
+            (Dialect::PostgreSQL, Type::Integer) => "BIGINT",
+            (Dialect::PostgreSQL, Type::String) => "TEXT",
+            (Dialect::SQLite, Type::Binary) => "BLOB",
+            (Dialect::SQLite, Type::Float) => "REAL",
+            (Dialect::SQLite, Type::Integer) => "INTEGER",
+            (Dialect::SQLite, Type::String) => "TEXT",
+        }
+    }
+
+    fn quote<T: AsRef<str>>(&self, name: T) -> String {
+        let delimiter = match self.dialect {
+            Dialect::MySQL | Dialect::SQLite => '`',
+            Dialect::PostgreSQL => '"',
+        };
+        let mut result = String::new();
+        result.push(delimiter);
+        for character in name.as_ref().chars() {
+            if character == delimiter {
+                result.push(delimiter);
+            }
+            result.push(character);
+        }
+        result.push(delimiter);
+        result
+    }
 }
 
 impl Buffer {
@@ -69,19 +315,23 @@ impl Buffer {
     }
 }
 
+fn is_name(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 macro_rules! string {
     ($($kind:path),*) => (
         $(
             impl<'l> $kind for &'l str {
                 #[inline]
-                fn compile(&self) -> Result<String> {
+                fn compile_with(&self, _: &mut Context) -> Result<String> {
                     Ok(self.to_string())
                 }
             }
 
             impl $kind for String {
                 #[inline]
-                fn compile(&self) -> Result<String> {
+                fn compile_with(&self, _: &mut Context) -> Result<String> {
                     Ok(self.clone())
                 }
             }
@@ -119,5 +369,6 @@ macro_rules! push(
 
 pub mod clause;
 pub mod definition;
+pub mod expression;
 pub mod operation;
 pub mod statement;
@@ -0,0 +1,152 @@
+//! Comparison operations.
+
+use Result;
+use grammar::definition::Column;
+use grammar::expression::{Binary, Case, Cast, Function, Json, Over, Unary};
+use grammar::{Condition, Context, Expression, Operation};
+
+/// A comparison operation.
+#[derive(Debug)]
+pub struct Compare(Box<Expression>, Comparison, Box<Expression>);
+
+/// A comparison.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Comparison {
+    /// The equality.
+    Equal,
+    /// The inequality.
+    NotEqual,
+    /// The strict lower bound.
+    LessThan,
+    /// The lower bound.
+    LessOrEqual,
+    /// The strict upper bound.
+    GreaterThan,
+    /// The upper bound.
+    GreaterOrEqual,
+}
+
+/// An object that can be compared.
+///
+/// The compared value is an expression, and a string slice is therefore
+/// embedded as raw SQL. Literals should be wrapped in `value`, which escapes
+/// them or binds them as parameters.
+pub trait Comparable where Self: Sized {
+    /// Set a comparison.
+    fn compare<T>(self, Comparison, T) -> Compare where T: Expression + 'static;
+
+    /// Set an equality comparison.
+    #[inline]
+    fn equal<T>(self, value: T) -> Compare where T: Expression + 'static {
+        self.compare(Comparison::Equal, value)
+    }
+
+    /// Set an inequality comparison.
+    #[inline]
+    fn not_equal<T>(self, value: T) -> Compare where T: Expression + 'static {
+        self.compare(Comparison::NotEqual, value)
+    }
+
+    /// Set a strict-lower-bound comparison.
+    #[inline]
+    fn less_than<T>(self, value: T) -> Compare where T: Expression + 'static {
+        self.compare(Comparison::LessThan, value)
+    }
+
+    /// Set a lower-bound comparison.
+    #[inline]
+    fn less_or_equal<T>(self, value: T) -> Compare where T: Expression + 'static {
+        self.compare(Comparison::LessOrEqual, value)
+    }
+
+    /// Set a strict-upper-bound comparison.
+    #[inline]
+    fn greater_than<T>(self, value: T) -> Compare where T: Expression + 'static {
+        self.compare(Comparison::GreaterThan, value)
+    }
+
+    /// Set an upper-bound comparison.
+    #[inline]
+    fn greater_or_equal<T>(self, value: T) -> Compare where T: Expression + 'static {
+        self.compare(Comparison::GreaterOrEqual, value)
+    }
+}
+
+impl Condition for Compare {
+    #[inline]
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        Operation::compile_with(self, context)
+    }
+}
+
+impl Operation for Compare {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let operator = match self.1 {
+            Comparison::Equal => "=",
+            Comparison::NotEqual => "<>",
+            Comparison::LessThan => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::GreaterThan => ">",
+            Comparison::GreaterOrEqual => ">=",
+        };
+        let left = try!(self.0.compile_with(context));
+        let right = try!(self.2.compile_with(context));
+        Ok(format!("{} {} {}", left, operator, right))
+    }
+}
+
+macro_rules! implement(
+    ($($kind:ty),*) => (
+        $(
+            impl Comparable for $kind {
+                #[inline]
+                fn compare<T>(self, comparison: Comparison, value: T) -> Compare
+                    where T: Expression + 'static
+                {
+                    Compare(Box::new(self), comparison, Box::new(value))
+                }
+            }
+        )*
+    );
+);
+
+implement!(Binary, Case, Cast, Column, Function, Json, Over, Unary);
+
+impl<'l> Comparable for &'l str {
+    #[inline]
+    fn compare<T>(self, comparison: Comparison, value: T) -> Compare
+        where T: Expression + 'static
+    {
+        Compare(Box::new(self.to_string()), comparison, Box::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Operation;
+    use prelude::*;
+
+    #[test]
+    fn from_column() {
+        assert_eq!(column("foo").greater_than(42).compile().unwrap(), "`foo` > 42");
+        assert_eq!(column("foo").not_equal(42).compile().unwrap(), "`foo` <> 42");
+    }
+
+    #[test]
+    fn from_string() {
+        assert_eq!("foo".less_or_equal("bar").compile().unwrap(), "foo <= bar");
+    }
+
+    #[test]
+    fn from_value() {
+        assert_eq!(column("foo").equal(value("bar")).compile().unwrap(), "`foo` = 'bar'");
+        assert_eq!(column("foo").equal(value("O'Brien")).compile().unwrap(),
+                   "`foo` = 'O''Brien'");
+    }
+
+    #[test]
+    fn raw() {
+        assert_eq!(column("foo").equal("bar").compile().unwrap(), "`foo` = bar");
+        assert_eq!(column("foo").equal(column("bar")).compile().unwrap(), "`foo` = `bar`");
+    }
+}
@@ -0,0 +1,83 @@
+//! JSON containment operations.
+
+use grammar::definition::Column;
+use grammar::expression::{Function, Json};
+use grammar::{Condition, Context, Expression, Operation};
+use {Dialect, Result};
+
+/// A JSON containment operation.
+#[derive(Debug)]
+pub struct Contain(Box<Expression>, Box<Expression>);
+
+/// An object that can contain JSON documents.
+pub trait Containable {
+    /// Set a document that should be contained.
+    fn contains_json<T>(self, T) -> Contain where T: Expression + 'static;
+}
+
+impl Condition for Contain {
+    #[inline]
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        Operation::compile_with(self, context)
+    }
+}
+
+impl Operation for Contain {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        if context.dialect() == Dialect::SQLite {
+            raise!("expected no JSON containment in SQLite");
+        }
+        let left = try!(self.0.compile_with(context));
+        let right = try!(self.1.compile_with(context));
+        Ok(match context.dialect() {
+            Dialect::MySQL => format!("JSON_CONTAINS({}, {})", left, right),
+            _ => format!("{} @> {}", left, right),
+        })
+    }
+}
+
+macro_rules! implement(
+    ($([$($lifetime:tt)*] $kind:ty, $subject:ident => $convert:expr),*) => (
+        $(
+            impl<$($lifetime)*> Containable for $kind {
+                #[inline]
+                fn contains_json<T>(self, document: T) -> Contain where T: Expression + 'static {
+                    let $subject = self;
+                    Contain(Box::new($convert), Box::new(document))
+                }
+            }
+        )*
+    );
+);
+
+implement!([] Column, subject => subject,
+           [] Function, subject => subject,
+           [] Json, subject => subject,
+           ['l] &'l str, subject => subject.to_string());
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Context, Operation};
+    use prelude::*;
+
+    #[test]
+    fn dialects() {
+        let operation = column("foo").contains_json(value("{\"bar\": 42}"));
+        assert!(operation.compile().is_err());
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(operation.compile_with(&mut context).unwrap(),
+                   "\"foo\" @> '{\"bar\": 42}'");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(operation.compile_with(&mut context).unwrap(),
+                   "JSON_CONTAINS(`foo`, '{\"bar\": 42}')");
+    }
+
+    #[test]
+    fn from_string() {
+        let operation = "foo".contains_json(value("[1]"));
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(operation.compile_with(&mut context).unwrap(), "foo @> '[1]'");
+    }
+}
@@ -2,7 +2,8 @@
 
 use Result;
 use grammar::definition::Column;
-use grammar::{Condition, Expression, Operation};
+use grammar::expression::Value;
+use grammar::{Condition, Context, Expression, Operation};
 
 /// A `LIKE` operation.
 #[derive(Debug)]
@@ -16,15 +17,16 @@ pub trait Likable {
 
 impl Condition for Like {
     #[inline]
-    fn compile(&self) -> Result<String> {
-        Operation::compile(self)
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        Operation::compile_with(self, context)
     }
 }
 
 impl Operation for Like {
     #[inline]
-    fn compile(&self) -> Result<String> {
-        Ok(format!("{} LIKE '{}'", try!(self.0.compile()), self.1))
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let pattern = try!(Value::String(self.1.clone()).compile_with(context));
+        Ok(format!("{} LIKE {}", try!(self.0.compile_with(context)), pattern))
     }
 }
 
@@ -56,4 +58,9 @@ mod tests {
     fn from_string() {
         assert_eq!("foo".like("bar").compile().unwrap(), "foo LIKE 'bar'");
     }
+
+    #[test]
+    fn escape() {
+        assert_eq!("foo".like("'bar'").compile().unwrap(), "foo LIKE '''bar'''");
+    }
 }
@@ -0,0 +1,74 @@
+//! The full-text `MATCH` operation.
+
+use grammar::definition::Column;
+use grammar::expression::Value;
+use grammar::{Condition, Context, Expression, Operation};
+use {Dialect, Result};
+
+/// A full-text `MATCH` operation.
+#[derive(Debug)]
+pub struct Match(Box<Expression>, String);
+
+/// An object that can be searched in.
+pub trait Matchable {
+    /// Set a full-text query.
+    fn full_text_match<T: ToString>(self, T) -> Match;
+}
+
+impl Condition for Match {
+    #[inline]
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        Operation::compile_with(self, context)
+    }
+}
+
+impl Operation for Match {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let subject = try!(self.0.compile_with(context));
+        let query = try!(Value::String(self.1.clone()).compile_with(context));
+        Ok(match context.dialect() {
+            Dialect::MySQL => format!("MATCH ({}) AGAINST ({})", subject, query),
+            Dialect::PostgreSQL => format!("to_tsvector({}) @@ to_tsquery({})", subject, query),
+            Dialect::SQLite => format!("{} MATCH {}", subject, query),
+        })
+    }
+}
+
+impl Matchable for Column {
+    #[inline]
+    fn full_text_match<T: ToString>(self, query: T) -> Match {
+        Match(Box::new(self), query.to_string())
+    }
+}
+
+impl<'l> Matchable for &'l str {
+    #[inline]
+    fn full_text_match<T: ToString>(self, query: T) -> Match {
+        Match(Box::new(self.to_string()), query.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Context, Operation};
+    use prelude::*;
+
+    #[test]
+    fn dialects() {
+        let operation = column("foo").full_text_match("bar");
+        assert_eq!(operation.compile().unwrap(), "`foo` MATCH 'bar'");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(operation.compile_with(&mut context).unwrap(),
+                   "to_tsvector(\"foo\") @@ to_tsquery('bar')");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(operation.compile_with(&mut context).unwrap(),
+                   "MATCH (`foo`) AGAINST ('bar')");
+    }
+
+    #[test]
+    fn from_string() {
+        assert_eq!("foo".full_text_match("bar").compile().unwrap(), "foo MATCH 'bar'");
+    }
+}
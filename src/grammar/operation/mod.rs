@@ -1,6 +1,22 @@
 //! Operations.
 
+pub mod compare;
+pub mod contain;
 pub mod like;
+pub mod matches;
+pub mod pattern;
 
+pub use self::compare::Comparable;
+pub use self::compare::Compare;
+pub use self::compare::Comparison;
+pub use self::contain::Containable;
+pub use self::contain::Contain;
 pub use self::like::Likable;
 pub use self::like::Like;
+pub use self::matches::Match;
+pub use self::matches::Matchable;
+pub use self::pattern::Globbable;
+pub use self::pattern::ILikable;
+pub use self::pattern::Pattern;
+pub use self::pattern::Regexable;
+pub use self::pattern::Similarable;
@@ -0,0 +1,224 @@
+//! Pattern-matching operations other than `LIKE`.
+
+use grammar::definition::Column;
+use grammar::expression::Value;
+use grammar::{Condition, Context, Expression, Operation};
+use {Dialect, Result};
+
+/// A pattern-matching operation.
+#[derive(Debug)]
+pub struct Pattern {
+    subject: Box<Expression>,
+    kind: Kind,
+    negated: bool,
+    pattern: String,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Kind {
+    Glob,
+    ILike,
+    IRegexp,
+    Regexp,
+    SimilarTo,
+}
+
+/// An object that can be matched with a `GLOB` pattern.
+pub trait Globbable {
+    /// Set a matcher.
+    fn glob<T: ToString>(self, T) -> Pattern;
+
+    /// Set a negated matcher.
+    fn not_glob<T: ToString>(self, T) -> Pattern;
+}
+
+/// An object that can be matched with an `ILIKE` pattern.
+pub trait ILikable {
+    /// Set a matcher.
+    fn ilike<T: ToString>(self, T) -> Pattern;
+
+    /// Set a negated matcher.
+    fn not_ilike<T: ToString>(self, T) -> Pattern;
+}
+
+/// An object that can be matched with a regular expression.
+pub trait Regexable {
+    /// Set a matcher.
+    fn regexp<T: ToString>(self, T) -> Pattern;
+
+    /// Set a negated matcher.
+    fn not_regexp<T: ToString>(self, T) -> Pattern;
+
+    /// Set a case-insensitive matcher.
+    fn iregexp<T: ToString>(self, T) -> Pattern;
+
+    /// Set a negated case-insensitive matcher.
+    fn not_iregexp<T: ToString>(self, T) -> Pattern;
+}
+
+/// An object that can be matched with a `SIMILAR TO` pattern.
+pub trait Similarable {
+    /// Set a matcher.
+    fn similar_to<T: ToString>(self, T) -> Pattern;
+
+    /// Set a negated matcher.
+    fn not_similar_to<T: ToString>(self, T) -> Pattern;
+}
+
+impl Condition for Pattern {
+    #[inline]
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        Operation::compile_with(self, context)
+    }
+}
+
+impl Operation for Pattern {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let dialect = context.dialect();
+        let operator = match (self.kind, dialect, self.negated) {
+            (Kind::Glob, Dialect::SQLite, false) => "GLOB",
+            (Kind::Glob, Dialect::SQLite, true) => "NOT GLOB",
+            (Kind::ILike, Dialect::PostgreSQL, false) => "ILIKE",
+            (Kind::ILike, Dialect::PostgreSQL, true) => "NOT ILIKE",
+            (Kind::IRegexp, Dialect::PostgreSQL, false) => "~*",
+            (Kind::IRegexp, Dialect::PostgreSQL, true) => "!~*",
+            (Kind::Regexp, Dialect::PostgreSQL, false) => "~",
+            (Kind::Regexp, Dialect::PostgreSQL, true) => "!~",
+            (Kind::Regexp, _, false) => "REGEXP",
+            (Kind::Regexp, _, true) => "NOT REGEXP",
+            (Kind::SimilarTo, Dialect::PostgreSQL, false) => "SIMILAR TO",
+            (Kind::SimilarTo, Dialect::PostgreSQL, true) => "NOT SIMILAR TO",
+            (kind, dialect, _) => raise!(format!("expected no {:?} operation in {:?}",
+                                                 kind, dialect)),
+        };
+        let subject = try!(self.subject.compile_with(context));
+        let pattern = try!(Value::String(self.pattern.clone()).compile_with(context));
+        Ok(format!("{} {} {}", subject, operator, pattern))
+    }
+}
+
+fn new<T: ToString>(subject: Box<Expression>, kind: Kind, negated: bool, pattern: T) -> Pattern {
+    Pattern { subject: subject, kind: kind, negated: negated, pattern: pattern.to_string() }
+}
+
+macro_rules! implement(
+    ([$($lifetime:tt),*] $kind:ty, $subject:ident => $convert:expr) => (
+        impl<$($lifetime),*> Globbable for $kind {
+            #[inline]
+            fn glob<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::Glob, false, pattern)
+            }
+
+            #[inline]
+            fn not_glob<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::Glob, true, pattern)
+            }
+        }
+
+        impl<$($lifetime),*> ILikable for $kind {
+            #[inline]
+            fn ilike<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::ILike, false, pattern)
+            }
+
+            #[inline]
+            fn not_ilike<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::ILike, true, pattern)
+            }
+        }
+
+        impl<$($lifetime),*> Regexable for $kind {
+            #[inline]
+            fn regexp<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::Regexp, false, pattern)
+            }
+
+            #[inline]
+            fn not_regexp<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::Regexp, true, pattern)
+            }
+
+            #[inline]
+            fn iregexp<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::IRegexp, false, pattern)
+            }
+
+            #[inline]
+            fn not_iregexp<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::IRegexp, true, pattern)
+            }
+        }
+
+        impl<$($lifetime),*> Similarable for $kind {
+            #[inline]
+            fn similar_to<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::SimilarTo, false, pattern)
+            }
+
+            #[inline]
+            fn not_similar_to<T: ToString>(self, pattern: T) -> Pattern {
+                let $subject = self;
+                new(Box::new($convert), Kind::SimilarTo, true, pattern)
+            }
+        }
+    );
+);
+
+implement!([] Column, subject => subject);
+implement!(['l] &'l str, subject => subject.to_string());
+
+#[cfg(test)]
+mod tests {
+    use grammar::{Context, Operation};
+    use prelude::*;
+
+    #[test]
+    fn glob() {
+        assert_eq!(column("foo").glob("b*r").compile().unwrap(), "`foo` GLOB 'b*r'");
+        assert_eq!("foo".not_glob("b*r").compile().unwrap(), "foo NOT GLOB 'b*r'");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(column("foo").glob("b*r").compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn ilike() {
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(column("foo").not_ilike("b%").compile_with(&mut context).unwrap(),
+                   "\"foo\" NOT ILIKE 'b%'");
+
+        assert!(column("foo").ilike("b%").compile().is_err());
+    }
+
+    #[test]
+    fn regexp() {
+        assert_eq!(column("foo").regexp("^b").compile().unwrap(), "`foo` REGEXP '^b'");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(column("foo").not_iregexp("^b").compile_with(&mut context).unwrap(),
+                   "\"foo\" !~* '^b'");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!("foo".not_regexp("^b").compile_with(&mut context).unwrap(),
+                   "foo NOT REGEXP '^b'");
+        assert!("foo".iregexp("^b").compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn similar_to() {
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(column("foo").similar_to("b(a|r)").compile_with(&mut context).unwrap(),
+                   "\"foo\" SIMILAR TO 'b(a|r)'");
+
+        assert!(column("foo").similar_to("b(a|r)").compile().is_err());
+    }
+}
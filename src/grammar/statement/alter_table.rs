@@ -0,0 +1,152 @@
+use grammar::definition::Column;
+use grammar::{Buffer, Context, Definition, Statement};
+use {Dialect, Result};
+
+/// An `ALTER TABLE` statement.
+#[derive(Clone, Debug, Default)]
+pub struct AlterTable {
+    name: Option<String>,
+    actions: Option<Vec<Action>>,
+}
+
+#[derive(Clone, Debug)]
+enum Action {
+    AddColumn(Column),
+    DropColumn(String),
+    RenameColumn(String, String),
+    RenameTo(String),
+}
+
+impl AlterTable {
+    /// Create an `ALTER TABLE` statement.
+    #[inline]
+    pub fn new<T: ToString>(name: T) -> Self {
+        AlterTable::default().name(name)
+    }
+
+    /// Set the name.
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Add a column.
+    pub fn add_column(mut self, column: Column) -> Self {
+        push!(self.actions, Action::AddColumn(column));
+        self
+    }
+
+    /// Drop a column.
+    pub fn drop_column<T: ToString>(mut self, name: T) -> Self {
+        push!(self.actions, Action::DropColumn(name.to_string()));
+        self
+    }
+
+    /// Rename a column.
+    pub fn rename_column<T: ToString, U: ToString>(mut self, from: T, to: U) -> Self {
+        push!(self.actions, Action::RenameColumn(from.to_string(), to.to_string()));
+        self
+    }
+
+    /// Rename the table.
+    pub fn rename_to<T: ToString>(mut self, name: T) -> Self {
+        push!(self.actions, Action::RenameTo(name.to_string()));
+        self
+    }
+}
+
+impl Statement for AlterTable {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let actions = some!(self.actions);
+        if actions.len() > 1 {
+            match context.dialect() {
+                Dialect::SQLite => raise!("expected a single action to alter a table in SQLite"),
+                Dialect::PostgreSQL => {
+                    for action in actions {
+                        match action {
+                            &Action::RenameColumn(..) | &Action::RenameTo(_) => {
+                                raise!("expected renaming to be the only action in PostgreSQL")
+                            },
+                            _ => {},
+                        }
+                    }
+                },
+                Dialect::MySQL => {},
+            }
+        }
+        let mut buffer = Buffer::new();
+        buffer.push("ALTER TABLE");
+        buffer.push(context.quote(some!(self.name)));
+        buffer.push({
+            let mut buffer = Buffer::new();
+            for action in actions {
+                buffer.push(match action {
+                    &Action::AddColumn(ref column) => {
+                        let column = try!(context.unbound(|context| {
+                            column.compile_with(context)
+                        }));
+                        format!("ADD COLUMN {}", column)
+                    },
+                    &Action::DropColumn(ref name) => {
+                        format!("DROP COLUMN {}", context.quote(name))
+                    },
+                    &Action::RenameColumn(ref from, ref to) => {
+                        format!("RENAME COLUMN {} TO {}", context.quote(from), context.quote(to))
+                    },
+                    &Action::RenameTo(ref name) => {
+                        format!("RENAME TO {}", context.quote(name))
+                    },
+                });
+            }
+            buffer.join(", ")
+        });
+        Ok(buffer.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Context;
+    use prelude::*;
+
+    #[test]
+    fn add_column() {
+        let statement = alter_table("foo").add_column(column("bar").float().not_null());
+        assert_eq!(statement.compile().unwrap(),
+                   "ALTER TABLE `foo` ADD COLUMN `bar` REAL NOT NULL");
+    }
+
+    #[test]
+    fn rename() {
+        let statement = alter_table("foo").rename_column("bar", "baz");
+        assert_eq!(statement.compile().unwrap(), "ALTER TABLE `foo` RENAME COLUMN `bar` TO `baz`");
+
+        let statement = alter_table("foo").rename_to("bar");
+        assert_eq!(statement.compile().unwrap(), "ALTER TABLE `foo` RENAME TO `bar`");
+    }
+
+    #[test]
+    fn multiple() {
+        let statement = alter_table("foo").drop_column("bar").add_column(column("baz").string());
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "ALTER TABLE \"foo\" DROP COLUMN \"bar\", ADD COLUMN \"baz\" TEXT");
+
+        let statement = statement.rename_to("qux");
+        assert!(statement.compile_with(&mut context).is_err());
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(statement.compile_with(&mut context).is_ok());
+    }
+
+    #[test]
+    fn bound() {
+        let definition = column("bar").string().check(column("bar").not_equal(value("")));
+        let statement = alter_table("foo").add_column(definition);
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("ALTER TABLE `foo` ADD COLUMN `bar` TEXT CHECK (`bar` <> '')".to_string(),
+                    vec![]));
+    }
+}
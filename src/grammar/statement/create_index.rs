@@ -0,0 +1,169 @@
+use grammar::clause::Where;
+use grammar::{Buffer, Clause, Condition, Context, Expression, Statement};
+use {Dialect, Result};
+
+/// A `CREATE INDEX` statement.
+#[derive(Debug, Default)]
+pub struct CreateIndex {
+    name: Option<String>,
+    table: Option<String>,
+    unique: Option<()>,
+    if_not_exists: Option<()>,
+    columns: Option<Vec<Box<Expression>>>,
+    so_that: Option<Where>,
+}
+
+impl CreateIndex {
+    /// Create a `CREATE INDEX` statement.
+    #[inline]
+    pub fn new<T: ToString>(name: T) -> Self {
+        CreateIndex::default().name(name)
+    }
+
+    /// Set the name.
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the table.
+    pub fn table<T: ToString>(mut self, name: T) -> Self {
+        self.table = Some(name.to_string());
+        self
+    }
+
+    /// Mark that it should be unique.
+    pub fn unique(mut self) -> Self {
+        self.unique = Some(());
+        self
+    }
+
+    /// Mark that it should be applied only if the index does not exist.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = Some(());
+        self
+    }
+
+    /// Add a column or an expression.
+    pub fn column<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        push!(self.columns, Box::new(expression));
+        self
+    }
+
+    /// Add a condition making the index partial.
+    pub fn so_that<T>(mut self, condition: T) -> Self where T: Condition + 'static {
+        self.so_that = Some(match self.so_that.take() {
+            Some(so_that) => so_that.and(condition),
+            _ => Where::default().and(condition),
+        });
+        self
+    }
+}
+
+impl Statement for CreateIndex {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let mut buffer = Buffer::new();
+        if let Some(_) = self.unique {
+            buffer.push("CREATE UNIQUE INDEX");
+        } else {
+            buffer.push("CREATE INDEX");
+        }
+        if let Some(_) = self.if_not_exists {
+            if context.dialect() == Dialect::MySQL {
+                raise!("expected no IF NOT EXISTS for an index in MySQL");
+            }
+            buffer.push("IF NOT EXISTS");
+        }
+        buffer.push(context.quote(some!(self.name)));
+        buffer.push("ON");
+        buffer.push(context.quote(some!(self.table)));
+        buffer.push({
+            let mut buffer = Buffer::new();
+            for expression in some!(self.columns) {
+                let value = try!(context.unbound(|context| expression.compile_with(context)));
+                if context.dialect() == Dialect::SQLite || expression.is_column() {
+                    buffer.push(value);
+                } else {
+                    buffer.push(format!("({})", value));
+                }
+            }
+            format!("({})", buffer.join(", "))
+        });
+        if let Some(ref clause) = self.so_that {
+            if context.dialect() == Dialect::MySQL {
+                raise!("expected no partial indices in MySQL");
+            }
+            buffer.push(try!(context.unbound(|context| clause.compile_with(context))));
+        }
+        Ok(buffer.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Context;
+    use prelude::*;
+
+    #[test]
+    fn columns() {
+        let statement = create_index("foo").table("bar").column(column("baz"))
+                                                        .column(column("qux").descend());
+
+        assert_eq!(statement.compile().unwrap(),
+                   "CREATE INDEX `foo` ON `bar` (`baz`, `qux` DESC)");
+    }
+
+    #[test]
+    fn expression() {
+        let statement = create_index("foo").table("bar").column("lower(baz)".ascend());
+        assert_eq!(statement.compile().unwrap(), "CREATE INDEX `foo` ON `bar` (lower(baz) ASC)");
+
+        let statement = create_index("foo").table("bar").column(column("baz"))
+                                                        .column(column("qux") + column("quux"))
+                                                        .column(lower(column("baz")));
+
+        assert_eq!(statement.compile().unwrap(),
+                   "CREATE INDEX `foo` ON `bar` (`baz`, `qux` + `quux`, LOWER(`baz`))");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "CREATE INDEX \"foo\" ON \"bar\" (\"baz\", (\"qux\" + \"quux\"), \
+                    (LOWER(\"baz\")))");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "CREATE INDEX `foo` ON `bar` (`baz`, (`qux` + `quux`), (LOWER(`baz`)))");
+
+        let statement = create_index("foo").table("bar").column("baz + qux");
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "CREATE INDEX \"foo\" ON \"bar\" ((baz + qux))");
+    }
+
+    #[test]
+    fn partial() {
+        let statement = create_index("foo").unique().if_not_exists().table("bar")
+                                           .column(column("baz"))
+                                           .so_that(column("qux").greater_than(0));
+
+        assert_eq!(statement.compile().unwrap(),
+                   "CREATE UNIQUE INDEX IF NOT EXISTS `foo` ON `bar` (`baz`) WHERE `qux` > 0");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(statement.compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn bound() {
+        let statement = create_index("foo").table("bar").column(column("baz"))
+                                           .so_that(column("qux").equal(value("quux")));
+
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("CREATE INDEX `foo` ON `bar` (`baz`) WHERE `qux` = 'quux'".to_string(),
+                    vec![]));
+
+        let statement = create_index("foo").table("bar").column(column("baz") + value(1));
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("CREATE INDEX `foo` ON `bar` (`baz` + 1)".to_string(), vec![]));
+    }
+}
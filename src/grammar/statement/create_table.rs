@@ -1,13 +1,22 @@
-use Result;
+use std::rc::Rc;
+
 use grammar::definition::Column;
-use grammar::{Buffer, Definition, Statement};
+use grammar::statement::Select;
+use grammar::{Buffer, Context, Definition, Statement, is_name};
+use {Dialect, Result};
 
 /// A `CREATE TABLE` statement.
 #[derive(Clone, Debug, Default)]
 pub struct CreateTable {
     name: Option<String>,
+    temporary: Option<()>,
+    unlogged: Option<()>,
     if_not_exists: Option<()>,
     columns: Option<Vec<Column>>,
+    query: Option<Rc<Select>>,
+    without_rowid: Option<()>,
+    strict: Option<()>,
+    engine: Option<String>,
 }
 
 impl CreateTable {
@@ -23,6 +32,18 @@ impl CreateTable {
         self
     }
 
+    /// Mark that it should be temporary.
+    pub fn temporary(mut self) -> Self {
+        self.temporary = Some(());
+        self
+    }
+
+    /// Mark that it should be unlogged, which is specific to PostgreSQL.
+    pub fn unlogged(mut self) -> Self {
+        self.unlogged = Some(());
+        self
+    }
+
     /// Mark that it should be applied only if the table does not exist.
     pub fn if_not_exists(mut self) -> Self {
         self.if_not_exists = Some(());
@@ -42,29 +63,104 @@ impl CreateTable {
         }
         self
     }
+
+    /// Set a query whose result should populate the table.
+    pub fn query(mut self, statement: Select) -> Self {
+        self.query = Some(Rc::new(statement));
+        self
+    }
+
+    /// Mark that it should have no row identifier, which is specific to SQLite.
+    pub fn without_rowid(mut self) -> Self {
+        self.without_rowid = Some(());
+        self
+    }
+
+    /// Mark that it should be strict, which is specific to SQLite.
+    pub fn strict(mut self) -> Self {
+        self.strict = Some(());
+        self
+    }
+
+    /// Set the storage engine, which is specific to MySQL.
+    pub fn engine<T: ToString>(mut self, name: T) -> Self {
+        self.engine = Some(name.to_string());
+        self
+    }
 }
 
 impl Statement for CreateTable {
-    fn compile(&self) -> Result<String> {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let dialect = context.dialect();
         let mut buffer = Buffer::new();
-        buffer.push("CREATE TABLE");
+        buffer.push("CREATE");
+        if let Some(_) = self.temporary {
+            buffer.push(match dialect {
+                Dialect::SQLite => "TEMP",
+                _ => "TEMPORARY",
+            });
+        }
+        if let Some(_) = self.unlogged {
+            if dialect != Dialect::PostgreSQL {
+                raise!("expected UNLOGGED only in PostgreSQL");
+            }
+            if let Some(_) = self.temporary {
+                raise!("expected a table to be either temporary or unlogged");
+            }
+            buffer.push("UNLOGGED");
+        }
+        buffer.push("TABLE");
         if let Some(_) = self.if_not_exists {
              buffer.push("IF NOT EXISTS");
         }
-        buffer.push(format!("`{}`", some!(self.name)));
-        buffer.push({
-            let mut buffer = Buffer::new();
-            for column in some!(self.columns) {
-                buffer.push(try!(column.compile()));
+        buffer.push(context.quote(some!(self.name)));
+        if self.query.is_none() {
+            buffer.push({
+                let mut buffer = Buffer::new();
+                for column in some!(self.columns) {
+                    buffer.push(try!(column.compile_with(context)));
+                }
+                format!("({})", buffer.join(", "))
+            });
+        } else if self.columns.is_some() {
+            raise!("expected either columns or a query to be set");
+        }
+        let mut options = Buffer::new();
+        if let Some(_) = self.without_rowid {
+            options.push("WITHOUT ROWID");
+        }
+        if let Some(_) = self.strict {
+            options.push("STRICT");
+        }
+        if options.len() > 0 {
+            if dialect != Dialect::SQLite {
+                raise!("expected WITHOUT ROWID and STRICT only in SQLite");
+            }
+            if self.query.is_some() {
+                raise!("expected no WITHOUT ROWID and STRICT with a query");
+            }
+            buffer.push(options.join(", "));
+        }
+        if let Some(ref name) = self.engine {
+            if dialect != Dialect::MySQL {
+                raise!("expected ENGINE only in MySQL");
             }
-            format!("({})", buffer.join(", "))
-        });
+            if !is_name(name) {
+                raise!(format!("expected a valid engine name, found “{}”", name));
+            }
+            buffer.push(format!("ENGINE={}", name));
+        }
+        if let Some(ref query) = self.query {
+            buffer.push("AS");
+            buffer.push(try!(context.unbound(|context| query.compile_with(context))));
+        }
         Ok(buffer.join(" "))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use grammar::Context;
     use prelude::*;
 
     #[test]
@@ -80,4 +176,65 @@ mod tests {
         let statement = create_table("foo").if_not_exists().column(column("bar").float());
         assert_eq!(statement.compile().unwrap(), "CREATE TABLE IF NOT EXISTS `foo` (`bar` REAL)");
     }
+
+    #[test]
+    fn temporary() {
+        let statement = create_table("foo").temporary().column(column("bar").float());
+        assert_eq!(statement.compile().unwrap(), "CREATE TEMP TABLE `foo` (`bar` REAL)");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "CREATE TEMPORARY TABLE `foo` (`bar` DOUBLE)");
+    }
+
+    #[test]
+    fn options() {
+        let statement = create_table("foo").column(column("bar").integer())
+                                           .without_rowid().strict();
+
+        assert_eq!(statement.compile().unwrap(),
+                   "CREATE TABLE `foo` (`bar` INTEGER) WITHOUT ROWID, STRICT");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert!(statement.compile_with(&mut context).is_err());
+
+        let statement = create_table("foo").column(column("bar").integer()).engine("InnoDB");
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "CREATE TABLE `foo` (`bar` BIGINT) ENGINE=InnoDB");
+
+        let statement = create_table("foo").unlogged().column(column("bar").integer());
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "CREATE UNLOGGED TABLE \"foo\" (\"bar\" BIGINT)");
+    }
+
+    #[test]
+    fn query() {
+        let statement = create_table("foo").query(select_from("bar").column("baz"));
+        assert_eq!(statement.compile().unwrap(),
+                   "CREATE TABLE `foo` AS SELECT `baz` FROM `bar`");
+
+        let statement = statement.column(column("baz").integer());
+        assert!(statement.compile().is_err());
+    }
+
+    #[test]
+    fn bound() {
+        let definition = column("bar").integer().check(column("bar").greater_than(0));
+        let statement = create_table("foo").column(definition);
+
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("CREATE TABLE `foo` (`bar` INTEGER CHECK (`bar` > 0))".to_string(), vec![]));
+
+        let query = select_from("bar").so_that(column("baz").equal(value("qux")));
+        let statement = create_table("foo").query(query);
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("CREATE TABLE `foo` AS SELECT * FROM `bar` WHERE `baz` = 'qux'".to_string(),
+                    vec![]));
+    }
 }
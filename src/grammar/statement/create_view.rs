@@ -0,0 +1,142 @@
+use grammar::statement::Select;
+use grammar::{Buffer, Context, Statement};
+use {Dialect, Result};
+
+/// A `CREATE VIEW` statement.
+#[derive(Debug, Default)]
+pub struct CreateView {
+    name: Option<String>,
+    or_replace: Option<()>,
+    temporary: Option<()>,
+    if_not_exists: Option<()>,
+    columns: Option<Vec<String>>,
+    query: Option<Select>,
+}
+
+impl CreateView {
+    /// Create a `CREATE VIEW` statement.
+    #[inline]
+    pub fn new<T: ToString>(name: T) -> Self {
+        CreateView::default().name(name)
+    }
+
+    /// Set the name.
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Mark that it should replace the view if the view exists.
+    pub fn or_replace(mut self) -> Self {
+        self.or_replace = Some(());
+        self
+    }
+
+    /// Mark that it should be temporary.
+    pub fn temporary(mut self) -> Self {
+        self.temporary = Some(());
+        self
+    }
+
+    /// Mark that it should be applied only if the view does not exist.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = Some(());
+        self
+    }
+
+    /// Add a column.
+    pub fn column<T: ToString>(mut self, name: T) -> Self {
+        push!(self.columns, name.to_string());
+        self
+    }
+
+    /// Add multiple columns.
+    pub fn columns<T: ToString>(mut self, names: &[T]) -> Self {
+        for name in names {
+            push!(self.columns, name.to_string());
+        }
+        self
+    }
+
+    /// Set the query.
+    pub fn query(mut self, statement: Select) -> Self {
+        self.query = Some(statement);
+        self
+    }
+}
+
+impl Statement for CreateView {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let dialect = context.dialect();
+        let mut buffer = Buffer::new();
+        buffer.push("CREATE");
+        if let Some(_) = self.or_replace {
+            if dialect == Dialect::SQLite {
+                raise!("expected no OR REPLACE for a view in SQLite");
+            }
+            buffer.push("OR REPLACE");
+        }
+        if let Some(_) = self.temporary {
+            if dialect == Dialect::MySQL {
+                raise!("expected no temporary views in MySQL");
+            }
+            buffer.push("TEMPORARY");
+        }
+        buffer.push("VIEW");
+        if let Some(_) = self.if_not_exists {
+            if dialect != Dialect::SQLite {
+                raise!("expected no IF NOT EXISTS for a view outside SQLite");
+            }
+            buffer.push("IF NOT EXISTS");
+        }
+        buffer.push(context.quote(some!(self.name)));
+        if let Some(ref columns) = self.columns {
+            buffer.push({
+                let mut buffer = Buffer::new();
+                for column in columns {
+                    buffer.push(context.quote(column));
+                }
+                format!("({})", buffer.join(", "))
+            });
+        }
+        buffer.push("AS");
+        let query = some!(self.query);
+        buffer.push(try!(context.unbound(|context| query.compile_with(context))));
+        Ok(buffer.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Context;
+    use prelude::*;
+
+    #[test]
+    fn columns() {
+        let statement = create_view("foo").temporary().if_not_exists().columns(&["bar"])
+                                          .query(select_from("baz").column("qux"));
+
+        assert_eq!(statement.compile().unwrap(),
+                   "CREATE TEMPORARY VIEW IF NOT EXISTS `foo` (`bar`) AS SELECT `qux` FROM `baz`");
+    }
+
+    #[test]
+    fn or_replace() {
+        let statement = create_view("foo").or_replace().query(select_from("bar"));
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "CREATE OR REPLACE VIEW \"foo\" AS SELECT * FROM \"bar\"");
+    }
+
+    #[test]
+    fn bound() {
+        let query = select_from("bar").so_that(column("baz").equal(value(42)));
+        let statement = create_view("foo").query(query);
+
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("CREATE VIEW `foo` AS SELECT * FROM `bar` WHERE `baz` = 42".to_string(),
+                    vec![]));
+    }
+}
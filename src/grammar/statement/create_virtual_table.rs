@@ -0,0 +1,137 @@
+use grammar::{Buffer, Context, Statement};
+use {Dialect, Result};
+
+/// A `CREATE VIRTUAL TABLE` statement for a full-text index of SQLite.
+#[derive(Clone, Debug, Default)]
+pub struct CreateVirtualTable {
+    name: Option<String>,
+    if_not_exists: Option<()>,
+    columns: Option<Vec<(String, bool)>>,
+    tokenize: Option<String>,
+    content: Option<String>,
+    content_rowid: Option<String>,
+}
+
+impl CreateVirtualTable {
+    /// Create a `CREATE VIRTUAL TABLE` statement.
+    #[inline]
+    pub fn new<T: ToString>(name: T) -> Self {
+        CreateVirtualTable::default().name(name)
+    }
+
+    /// Set the name.
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Mark that it should be applied only if the table does not exist.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = Some(());
+        self
+    }
+
+    /// Add a column.
+    pub fn column<T: ToString>(mut self, name: T) -> Self {
+        push!(self.columns, (name.to_string(), false));
+        self
+    }
+
+    /// Add multiple columns.
+    pub fn columns<T: ToString>(mut self, names: &[T]) -> Self {
+        for name in names {
+            push!(self.columns, (name.to_string(), false));
+        }
+        self
+    }
+
+    /// Add a column that is stored but not indexed.
+    pub fn unindexed_column<T: ToString>(mut self, name: T) -> Self {
+        push!(self.columns, (name.to_string(), true));
+        self
+    }
+
+    /// Set the tokenizer.
+    pub fn tokenize<T: ToString>(mut self, value: T) -> Self {
+        self.tokenize = Some(value.to_string());
+        self
+    }
+
+    /// Set the table holding the content, which is empty for no content.
+    pub fn content<T: ToString>(mut self, table: T) -> Self {
+        self.content = Some(table.to_string());
+        self
+    }
+
+    /// Set the column of the content table holding the row identifier.
+    pub fn content_rowid<T: ToString>(mut self, column: T) -> Self {
+        self.content_rowid = Some(column.to_string());
+        self
+    }
+}
+
+impl Statement for CreateVirtualTable {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        if context.dialect() != Dialect::SQLite {
+            raise!("expected virtual tables only in SQLite");
+        }
+        let mut buffer = Buffer::new();
+        buffer.push("CREATE VIRTUAL TABLE");
+        if let Some(_) = self.if_not_exists {
+            buffer.push("IF NOT EXISTS");
+        }
+        buffer.push(context.quote(some!(self.name)));
+        buffer.push("USING");
+        buffer.push({
+            let mut buffer = Buffer::new();
+            for &(ref column, unindexed) in some!(self.columns) {
+                if unindexed {
+                    buffer.push(format!("{} UNINDEXED", context.quote(column)));
+                } else {
+                    buffer.push(context.quote(column));
+                }
+            }
+            if let Some(ref value) = self.tokenize {
+                buffer.push(format!("tokenize = {}", try!(context.string(value))));
+            }
+            if let Some(ref value) = self.content {
+                buffer.push(format!("content = {}", try!(context.string(value))));
+            }
+            if let Some(ref value) = self.content_rowid {
+                buffer.push(format!("content_rowid = {}", try!(context.string(value))));
+            }
+            format!("fts5({})", buffer.join(", "))
+        });
+        Ok(buffer.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Context;
+    use prelude::*;
+
+    #[test]
+    fn options() {
+        let statement = create_virtual_table("foo").columns(&["bar", "baz"])
+                                                   .unindexed_column("qux")
+                                                   .tokenize("porter unicode61")
+                                                   .content("quux")
+                                                   .content_rowid("id");
+
+        assert_eq!(statement.compile().unwrap(),
+                   "CREATE VIRTUAL TABLE `foo` USING fts5(`bar`, `baz`, `qux` UNINDEXED, \
+                    tokenize = 'porter unicode61', content = 'quux', content_rowid = 'id')");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert!(statement.compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn bound() {
+        let statement = create_virtual_table("foo").if_not_exists().column("bar").content("");
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("CREATE VIRTUAL TABLE IF NOT EXISTS `foo` USING fts5(`bar`, content = '')"
+                    .to_string(), vec![]));
+    }
+}
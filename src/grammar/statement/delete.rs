@@ -1,10 +1,12 @@
 use Result;
-use grammar::{Buffer, Statement};
+use grammar::clause::Returning;
+use grammar::{Buffer, Clause, Context, Expression, Statement};
 
 /// A `DELETE` statement.
 #[derive(Debug, Default)]
 pub struct Delete {
     table: Option<String>,
+    returning: Option<Returning>,
 }
 
 impl Delete {
@@ -19,13 +21,25 @@ impl Delete {
         self.table = Some(name.to_string());
         self
     }
+
+    /// Add a returned expression.
+    pub fn returning<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        self.returning = Some(match self.returning.take() {
+            Some(returning) => returning.append(expression),
+            _ => Returning::default().append(expression),
+        });
+        self
+    }
 }
 
 impl Statement for Delete {
-    fn compile(&self) -> Result<String> {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
         let mut buffer = Buffer::new();
         buffer.push("DELETE FROM");
-        buffer.push(format!("`{}`", some!(self.table)));
+        buffer.push(context.quote(some!(self.table)));
+        if let Some(ref clause) = self.returning {
+            buffer.push(try!(clause.compile_with(context)));
+        }
         Ok(buffer.join(" "))
     }
 }
@@ -39,4 +53,10 @@ mod tests {
         let statement = delete_from("foo");
         assert_eq!(statement.compile().unwrap(), "DELETE FROM `foo`");
     }
+
+    #[test]
+    fn returning() {
+        let statement = delete_from("foo").returning("bar").returning(column("baz"));
+        assert_eq!(statement.compile().unwrap(), "DELETE FROM `foo` RETURNING bar, `baz`");
+    }
 }
@@ -0,0 +1,80 @@
+use grammar::{Buffer, Context, Statement};
+use {Dialect, Result};
+
+/// A `DROP INDEX` statement.
+#[derive(Clone, Debug, Default)]
+pub struct DropIndex {
+    name: Option<String>,
+    table: Option<String>,
+    if_exists: Option<()>,
+}
+
+impl DropIndex {
+    /// Create a `DROP INDEX` statement.
+    #[inline]
+    pub fn new<T: ToString>(name: T) -> Self {
+        DropIndex::default().name(name)
+    }
+
+    /// Set the name.
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the table, which is required by MySQL.
+    pub fn table<T: ToString>(mut self, name: T) -> Self {
+        self.table = Some(name.to_string());
+        self
+    }
+
+    /// Mark that it should be applied only if the index exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = Some(());
+        self
+    }
+}
+
+impl Statement for DropIndex {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let mut buffer = Buffer::new();
+        buffer.push("DROP INDEX");
+        if let Some(_) = self.if_exists {
+            if context.dialect() == Dialect::MySQL {
+                raise!("expected no IF EXISTS for an index in MySQL");
+            }
+            buffer.push("IF EXISTS");
+        }
+        buffer.push(context.quote(some!(self.name)));
+        match (context.dialect(), &self.table) {
+            (Dialect::MySQL, _) => {
+                buffer.push("ON");
+                buffer.push(context.quote(some!(self.table)));
+            },
+            (_, &Some(_)) => raise!("expected no table for dropping an index"),
+            _ => {},
+        }
+        Ok(buffer.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Context;
+    use prelude::*;
+
+    #[test]
+    fn if_exists() {
+        let statement = drop_index("foo").if_exists();
+        assert_eq!(statement.compile().unwrap(), "DROP INDEX IF EXISTS `foo`");
+    }
+
+    #[test]
+    fn table() {
+        let statement = drop_index("foo").table("bar");
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(), "DROP INDEX `foo` ON `bar`");
+    }
+}
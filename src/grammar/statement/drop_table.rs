@@ -0,0 +1,109 @@
+use grammar::{Buffer, Context, Statement};
+use {Dialect, Result};
+
+/// A `DROP TABLE` statement.
+#[derive(Clone, Debug, Default)]
+pub struct DropTable {
+    tables: Option<Vec<String>>,
+    if_exists: Option<()>,
+    behavior: Option<Behavior>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Behavior {
+    Cascade,
+    Restrict,
+}
+
+impl DropTable {
+    /// Create a `DROP TABLE` statement.
+    #[inline]
+    pub fn new<T: ToString>(name: T) -> Self {
+        DropTable::default().table(name)
+    }
+
+    /// Add a table.
+    pub fn table<T: ToString>(mut self, name: T) -> Self {
+        push!(self.tables, name.to_string());
+        self
+    }
+
+    /// Add multiple tables.
+    pub fn tables<T: ToString>(mut self, names: &[T]) -> Self {
+        for name in names {
+            push!(self.tables, name.to_string());
+        }
+        self
+    }
+
+    /// Mark that it should be applied only if the table exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = Some(());
+        self
+    }
+
+    /// Mark that dependent objects should be dropped too.
+    pub fn cascade(mut self) -> Self {
+        self.behavior = Some(Behavior::Cascade);
+        self
+    }
+
+    /// Mark that it should fail if there are dependent objects.
+    pub fn restrict(mut self) -> Self {
+        self.behavior = Some(Behavior::Restrict);
+        self
+    }
+}
+
+impl Statement for DropTable {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let tables = some!(self.tables);
+        let mut buffer = Buffer::new();
+        buffer.push("DROP TABLE");
+        if let Some(_) = self.if_exists {
+            buffer.push("IF EXISTS");
+        }
+        if tables.len() > 1 && context.dialect() == Dialect::SQLite {
+            raise!("expected a single table to drop in SQLite");
+        }
+        buffer.push({
+            let mut buffer = Buffer::new();
+            for table in tables {
+                buffer.push(context.quote(table));
+            }
+            buffer.join(", ")
+        });
+        if let Some(behavior) = self.behavior {
+            if context.dialect() == Dialect::SQLite {
+                raise!("expected no CASCADE or RESTRICT in SQLite");
+            }
+            buffer.push(match behavior {
+                Behavior::Cascade => "CASCADE",
+                Behavior::Restrict => "RESTRICT",
+            });
+        }
+        Ok(buffer.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Context;
+    use prelude::*;
+
+    #[test]
+    fn if_exists() {
+        let statement = drop_table("foo").if_exists();
+        assert_eq!(statement.compile().unwrap(), "DROP TABLE IF EXISTS `foo`");
+    }
+
+    #[test]
+    fn cascade() {
+        let statement = drop_table("foo").table("bar").cascade();
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "DROP TABLE \"foo\", \"bar\" CASCADE");
+    }
+}
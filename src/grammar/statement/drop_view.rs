@@ -0,0 +1,75 @@
+use grammar::{Buffer, Context, Statement};
+use {Dialect, Result};
+
+/// A `DROP VIEW` statement.
+#[derive(Clone, Debug, Default)]
+pub struct DropView {
+    views: Option<Vec<String>>,
+    if_exists: Option<()>,
+}
+
+impl DropView {
+    /// Create a `DROP VIEW` statement.
+    #[inline]
+    pub fn new<T: ToString>(name: T) -> Self {
+        DropView::default().view(name)
+    }
+
+    /// Add a view.
+    pub fn view<T: ToString>(mut self, name: T) -> Self {
+        push!(self.views, name.to_string());
+        self
+    }
+
+    /// Add multiple views.
+    pub fn views<T: ToString>(mut self, names: &[T]) -> Self {
+        for name in names {
+            push!(self.views, name.to_string());
+        }
+        self
+    }
+
+    /// Mark that it should be applied only if the view exists.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = Some(());
+        self
+    }
+}
+
+impl Statement for DropView {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let views = some!(self.views);
+        let mut buffer = Buffer::new();
+        buffer.push("DROP VIEW");
+        if let Some(_) = self.if_exists {
+            buffer.push("IF EXISTS");
+        }
+        if views.len() > 1 && context.dialect() == Dialect::SQLite {
+            raise!("expected a single view to drop in SQLite");
+        }
+        buffer.push({
+            let mut buffer = Buffer::new();
+            for view in views {
+                buffer.push(context.quote(view));
+            }
+            buffer.join(", ")
+        });
+        Ok(buffer.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grammar::Context;
+    use prelude::*;
+
+    #[test]
+    fn views() {
+        let statement = drop_view("foo").if_exists().view("bar");
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "DROP VIEW IF EXISTS `foo`, `bar`");
+    }
+}
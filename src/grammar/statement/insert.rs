@@ -1,14 +1,45 @@
-use Result;
-use grammar::{Buffer, Statement};
+use std::rc::Rc;
+
+use grammar::clause::{OnConflict, Returning};
+use grammar::expression::Placeholder;
+use grammar::statement::Select;
+use grammar::{Buffer, Clause, Context, Expression, Statement};
+use {Dialect, Result};
 
 /// An `INSERT` statement.
 #[derive(Clone, Debug, Default)]
 pub struct Insert {
     table: Option<String>,
+    replace: Option<()>,
+    resolution: Option<Resolution>,
     columns: Option<Vec<String>>,
     batch: Option<usize>,
+    rows: Option<Vec<Row>>,
+    default_values: Option<()>,
+    query: Option<Rc<Select>>,
+    on_conflict: Option<Rc<OnConflict>>,
+    returning: Option<Returning>,
+}
+
+/// A conflict resolution.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Resolution {
+    /// Abort the statement and undo its changes.
+    Abort,
+    /// Abort the statement and keep its prior changes.
+    Fail,
+    /// Skip the conflicting row.
+    Ignore,
+    /// Delete the conflicting rows before inserting.
+    Replace,
+    /// Abort the statement and roll back the transaction.
+    Rollback,
 }
 
+/// A row of values.
+#[derive(Clone, Debug, Default)]
+pub struct Row(Vec<Rc<Expression>>);
+
 impl Insert {
     /// Create an `INSERT` statement.
     #[inline]
@@ -22,6 +53,18 @@ impl Insert {
         self
     }
 
+    /// Mark that it should be a `REPLACE` statement.
+    pub fn replace(mut self) -> Self {
+        self.replace = Some(());
+        self
+    }
+
+    /// Set the conflict resolution.
+    pub fn or(mut self, resolution: Resolution) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
     /// Add a column.
     pub fn column<T: ToString>(mut self, name: T) -> Self {
         push!(self.columns, name.to_string());
@@ -41,41 +84,200 @@ impl Insert {
         self.batch = Some(count);
         self
     }
+
+    /// Add a row of values.
+    pub fn values(mut self, row: Row) -> Self {
+        push!(self.rows, row);
+        self
+    }
+
+    /// Mark that a single row of default values should be inserted.
+    pub fn default_values(mut self) -> Self {
+        self.default_values = Some(());
+        self
+    }
+
+    /// Set a query whose result should be inserted.
+    pub fn query(mut self, statement: Select) -> Self {
+        self.query = Some(Rc::new(statement));
+        self
+    }
+
+    /// Set the conflict handling.
+    pub fn on_conflict(mut self, clause: OnConflict) -> Self {
+        self.on_conflict = Some(Rc::new(clause));
+        self
+    }
+
+    /// Add a returned expression.
+    pub fn returning<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        self.returning = Some(match self.returning.take() {
+            Some(returning) => returning.append(expression),
+            _ => Returning::default().append(expression),
+        });
+        self
+    }
 }
 
 impl Statement for Insert {
-    fn compile(&self) -> Result<String> {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        let dialect = context.dialect();
+        if dialect == Dialect::MySQL && self.on_conflict.is_some() {
+            if self.replace.is_some() || self.resolution == Some(Resolution::Replace) {
+                raise!("expected no upsert with REPLACE in MySQL");
+            }
+        }
         let mut buffer = Buffer::new();
-        buffer.push("INSERT INTO");
-        buffer.push(format!("`{}`", some!(self.table)));
-        buffer.push({
-            let names = {
-                let mut buffer = Buffer::new();
-                for column in some!(self.columns) {
-                    buffer.push(format!("`{}`", column));
-                }
-                buffer
+        if let Some(_) = self.replace {
+            if self.resolution.is_some() {
+                raise!("expected either REPLACE or a conflict resolution");
+            }
+            if dialect == Dialect::PostgreSQL {
+                raise!("expected no REPLACE in PostgreSQL");
+            }
+            buffer.push("REPLACE INTO");
+        } else if let Some(resolution) = self.resolution {
+            match (dialect, resolution) {
+                (Dialect::SQLite, _) => buffer.push(match resolution {
+                    Resolution::Abort => "INSERT OR ABORT INTO",
+                    Resolution::Fail => "INSERT OR FAIL INTO",
+                    Resolution::Ignore => "INSERT OR IGNORE INTO",
+                    Resolution::Replace => "INSERT OR REPLACE INTO",
+                    Resolution::Rollback => "INSERT OR ROLLBACK INTO",
+                }),
+                (Dialect::MySQL, Resolution::Ignore) => buffer.push("INSERT IGNORE INTO"),
+                (Dialect::MySQL, Resolution::Replace) => buffer.push("REPLACE INTO"),
+                _ => raise!(format!("expected no {:?} conflict resolution in {:?}",
+                                    resolution, dialect)),
             };
-            let values = {
-                let mut buffer = Buffer::new();
-                for _ in 0..names.len() {
-                    buffer.push("?");
+        } else {
+            buffer.push("INSERT INTO");
+        }
+        buffer.push(context.quote(some!(self.table)));
+        if let Some(_) = self.default_values {
+            let conflicting = self.columns.is_some() || self.batch.is_some() ||
+                              self.rows.is_some() || self.query.is_some();
+            if conflicting {
+                raise!("expected no columns, batch, rows, or query with default values");
+            }
+            if dialect == Dialect::SQLite && self.on_conflict.is_some() {
+                raise!("expected no upsert with default values in SQLite");
+            }
+            buffer.push(match dialect {
+                Dialect::MySQL => "() VALUES ()",
+                _ => "DEFAULT VALUES",
+            });
+        } else if let Some(ref query) = self.query {
+            if self.batch.is_some() || self.rows.is_some() {
+                raise!("expected either a batch, rows, or a query to be set");
+            }
+            if let Some(ref columns) = self.columns {
+                if let Some(count) = query.width() {
+                    if count != columns.len() {
+                        raise!(format!("expected the query to have {} columns, found {}",
+                                       columns.len(), count));
+                    }
                 }
-                let one = format!("({})", buffer.join(", "));
+                buffer.push({
+                    let mut buffer = Buffer::new();
+                    for column in columns {
+                        buffer.push(context.quote(column));
+                    }
+                    format!("({})", buffer.join(", "))
+                });
+            }
+            // In SQLite, a query followed by an upsert clause needs a WHERE
+            // clause to be parsed unambiguously.
+            if dialect == Dialect::SQLite && self.on_conflict.is_some() {
+                buffer.push(try!(query.compile_filtered(context)));
+            } else {
+                buffer.push(try!(query.compile_with(context)));
+            }
+        } else if let Some(ref rows) = self.rows {
+            if let Some(_) = self.batch {
+                raise!("expected either a batch or rows to be set");
+            }
+            let count = match self.columns {
+                Some(ref columns) => {
+                    buffer.push({
+                        let mut buffer = Buffer::new();
+                        for column in columns {
+                            buffer.push(context.quote(column));
+                        }
+                        format!("({})", buffer.join(", "))
+                    });
+                    columns.len()
+                },
+                _ => rows[0].0.len(),
+            };
+            buffer.push({
                 let mut buffer = Buffer::new();
-                for _ in 0..self.batch.unwrap_or(1) {
-                    buffer.push(&one);
+                for row in rows {
+                    if row.0.len() != count {
+                        raise!(format!("expected rows to have {} values, found {}",
+                                       count, row.0.len()));
+                    }
+                    buffer.push(try!(row.compile_with(context)));
                 }
-                buffer
-            };
-            format!("({}) VALUES {}", names.join(", "), values.join(", "))
-        });
+                format!("VALUES {}", buffer.join(", "))
+            });
+        } else {
+            buffer.push({
+                let names = {
+                    let mut buffer = Buffer::new();
+                    for column in some!(self.columns) {
+                        buffer.push(context.quote(column));
+                    }
+                    buffer
+                };
+                let values = {
+                    let mut buffer = Buffer::new();
+                    for _ in 0..self.batch.unwrap_or(1) {
+                        let mut row = Buffer::new();
+                        for _ in 0..names.len() {
+                            row.push(try!(Placeholder.compile_with(context)));
+                        }
+                        buffer.push(format!("({})", row.join(", ")));
+                    }
+                    buffer
+                };
+                format!("({}) VALUES {}", names.join(", "), values.join(", "))
+            });
+        }
+        if let Some(ref clause) = self.on_conflict {
+            buffer.push(try!(clause.compile_with(context)));
+        }
+        if let Some(ref clause) = self.returning {
+            buffer.push(try!(clause.compile_with(context)));
+        }
         Ok(buffer.join(" "))
     }
 }
 
+impl Row {
+    /// Add a value.
+    pub fn value<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        self.0.push(Rc::new(expression));
+        self
+    }
+}
+
+impl Expression for Row {
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        if self.0.is_empty() {
+            raise!("expected at least one value in a row");
+        }
+        let mut buffer = Buffer::new();
+        for expression in &self.0 {
+            buffer.push(try!(expression.compile_with(context)));
+        }
+        Ok(format!("({})", buffer.join(", ")))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use grammar::Context;
     use prelude::*;
 
     #[test]
@@ -85,4 +287,169 @@ mod tests {
         assert_eq!(statement.compile().unwrap(),
                    "INSERT INTO `foo` (`bar`, `baz`) VALUES (?, ?), (?, ?), (?, ?)");
     }
+
+    #[test]
+    fn upsert() {
+        let clause = on_conflict(&["bar"]).do_update().set("baz", excluded("baz"));
+        let statement = insert_into("foo").columns(&["bar", "baz"]).on_conflict(clause);
+
+        assert_eq!(statement.compile().unwrap(),
+                   "INSERT INTO `foo` (`bar`, `baz`) VALUES (?, ?) \
+                    ON CONFLICT (`bar`) DO UPDATE SET `baz` = excluded.`baz`");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "INSERT INTO `foo` (`bar`, `baz`) VALUES (?, ?) \
+                    ON DUPLICATE KEY UPDATE `baz` = VALUES(`baz`)");
+    }
+
+    #[test]
+    fn resolution() {
+        let statement = insert_into("foo").or(Resolution::Ignore).column("bar");
+        assert_eq!(statement.compile().unwrap(), "INSERT OR IGNORE INTO `foo` (`bar`) VALUES (?)");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "INSERT IGNORE INTO `foo` (`bar`) VALUES (?)");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert!(statement.compile_with(&mut context).is_err());
+
+        let statement = insert_into("foo").or(Resolution::Rollback).column("bar");
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(statement.compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn replace() {
+        let statement = replace_into("foo").column("bar");
+        assert_eq!(statement.compile().unwrap(), "REPLACE INTO `foo` (`bar`) VALUES (?)");
+
+        let clause = on_conflict(&["bar"]).do_update().set("bar", excluded("bar"));
+        let statement = replace_into("foo").column("bar").on_conflict(clause);
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(statement.compile_with(&mut context).is_err());
+
+        let clause = on_conflict(&["bar"]).do_update().set("bar", excluded("bar"));
+        let statement = insert_into("foo").or(Resolution::Replace).column("bar")
+                                          .on_conflict(clause);
+        assert!(statement.compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn query() {
+        let statement = insert_into("foo").columns(&["bar", "baz"])
+                                          .query(select_from("qux").columns(&["bar", "baz"]));
+
+        assert_eq!(statement.compile().unwrap(),
+                   "INSERT INTO `foo` (`bar`, `baz`) SELECT `bar`, `baz` FROM `qux`");
+
+        let statement = insert_into("foo").columns(&["bar", "baz"])
+                                          .query(select_from("qux").column("bar"));
+
+        assert!(statement.compile().is_err());
+
+        let statement = insert_into("foo").query(select_from("qux"));
+        assert_eq!(statement.compile().unwrap(), "INSERT INTO `foo` SELECT * FROM `qux`");
+
+        let statement = statement.on_conflict(on_conflict(&["bar"]).do_nothing());
+        assert_eq!(statement.compile().unwrap(),
+                   "INSERT INTO `foo` SELECT * FROM `qux` WHERE true \
+                    ON CONFLICT (`bar`) DO NOTHING");
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "INSERT INTO \"foo\" SELECT * FROM \"qux\" ON CONFLICT (\"bar\") DO NOTHING");
+
+        let query = select_from("qux").so_that(column("bar").equal(1));
+        let statement = insert_into("foo").query(query)
+                                          .on_conflict(on_conflict(&["bar"]).do_nothing());
+
+        assert_eq!(statement.compile().unwrap(),
+                   "INSERT INTO `foo` SELECT * FROM `qux` WHERE `bar` = 1 \
+                    ON CONFLICT (`bar`) DO NOTHING");
+    }
+
+    #[test]
+    fn default_values() {
+        let statement = insert_into("foo").default_values();
+        assert_eq!(statement.compile().unwrap(), "INSERT INTO `foo` DEFAULT VALUES");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(), "INSERT INTO `foo` () VALUES ()");
+
+        let statement = insert_into("foo").default_values().column("bar");
+        assert!(statement.compile().is_err());
+
+        let statement = insert_into("foo").default_values()
+                                          .on_conflict(on_conflict(&["bar"]).do_nothing());
+
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "INSERT INTO \"foo\" DEFAULT VALUES ON CONFLICT (\"bar\") DO NOTHING");
+    }
+
+    #[test]
+    fn returning() {
+        let statement = insert_into("foo").column("bar").returning(column("baz"));
+        assert_eq!(statement.compile().unwrap(),
+                   "INSERT INTO `foo` (`bar`) VALUES (?) RETURNING `baz`");
+
+        let mut context = Context::new(Dialect::MySQL);
+        assert!(statement.compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn values() {
+        let statement = insert_into("foo").columns(&["bar", "baz"])
+                                          .values(row().value(placeholder()).value("'qux'"))
+                                          .values(row().value(column("bar"))
+                                                       .value("CURRENT_TIMESTAMP"));
+
+        assert_eq!(statement.compile().unwrap(),
+                   "INSERT INTO `foo` (`bar`, `baz`) \
+                    VALUES (?, 'qux'), (`bar`, CURRENT_TIMESTAMP)");
+
+        let statement = insert_into("foo").columns(&["bar", "baz"])
+                                          .values(row().value(placeholder()));
+
+        assert!(statement.compile().is_err());
+
+        let statement = insert_into("foo").values(row());
+        assert!(statement.compile().is_err());
+    }
+
+    #[test]
+    fn bound() {
+        let statement = insert_into("foo").columns(&["bar", "baz"])
+                                          .values(row().value(42).value("CURRENT_TIMESTAMP"));
+
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("INSERT INTO `foo` (`bar`, `baz`) VALUES (?, CURRENT_TIMESTAMP)".to_string(),
+                    vec![value(42)]));
+
+        let statement = insert_into("foo").columns(&["bar", "baz"]).batch(2);
+        assert!(statement.compile_bound(Dialect::SQLite).is_err());
+    }
+
+    #[test]
+    fn numbered() {
+        let mut context = Context::new(Dialect::PostgreSQL);
+        let statement = insert_into("foo").columns(&["bar", "baz"]).batch(2);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "INSERT INTO \"foo\" (\"bar\", \"baz\") VALUES ($1, $2), ($3, $4)");
+
+        let mut context = Context::new(Dialect::PostgreSQL).bind();
+        let statement = insert_into("foo").columns(&["bar", "baz"])
+                                          .values(row().value(1).value(2))
+                                          .on_conflict(on_conflict(&["bar"]).do_update()
+                                                                            .set("baz", 3));
+
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "INSERT INTO \"foo\" (\"bar\", \"baz\") VALUES ($1, $2) \
+                    ON CONFLICT (\"bar\") DO UPDATE SET \"baz\" = $3");
+        assert_eq!(context.parameters(), &[value(1), value(2), value(3)]);
+    }
 }
@@ -1,11 +1,27 @@
 //! Statements.
 
+mod alter_table;
+mod create_index;
 mod create_table;
+mod create_view;
+mod create_virtual_table;
 mod delete;
+mod drop_index;
+mod drop_table;
+mod drop_view;
 mod insert;
 mod select;
 
+pub use self::alter_table::AlterTable;
+pub use self::create_index::CreateIndex;
 pub use self::create_table::CreateTable;
+pub use self::create_view::CreateView;
+pub use self::create_virtual_table::CreateVirtualTable;
 pub use self::delete::Delete;
+pub use self::drop_index::DropIndex;
+pub use self::drop_table::DropTable;
+pub use self::drop_view::DropView;
 pub use self::insert::Insert;
+pub use self::insert::Resolution;
+pub use self::insert::Row;
 pub use self::select::Select;
@@ -1,15 +1,32 @@
-use Result;
+use {Dialect, Result};
 use grammar::clause::{OrderBy, Where};
-use grammar::{Buffer, Clause, Condition, Expression, Statement};
+use grammar::definition::{Column, Window};
+use grammar::{Buffer, Clause, Condition, Context, Definition, Expression, Statement};
 
 /// A `SELECT` statement.
 #[derive(Debug, Default)]
 pub struct Select {
     table: Option<String>,
-    columns: Option<Vec<String>>,
+    sources: Option<Vec<Box<Expression>>>,
+    columns: Option<Vec<Box<Expression>>>,
     so_that: Option<Where>,
+    windows: Option<Vec<(String, Window)>>,
     order_by: Option<OrderBy>,
     limit: Option<usize>,
+    lock: Option<Lock>,
+    wait: Option<Wait>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Lock {
+    Share,
+    Update,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Wait {
+    NoWait,
+    SkipLocked,
 }
 
 impl Select {
@@ -25,20 +42,32 @@ impl Select {
         self
     }
 
+    /// Add a table-valued source, such as `json_each`.
+    pub fn from<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        push!(self.sources, Box::new(expression));
+        self
+    }
+
     /// Add a column.
     pub fn column<T: ToString>(mut self, name: T) -> Self {
-        push!(self.columns, name.to_string());
+        push!(self.columns, Box::new(Column::new(name.to_string())));
         self
     }
 
     /// Add multiple columns.
     pub fn columns<T: ToString>(mut self, names: &[T]) -> Self {
         for name in names {
-            push!(self.columns, name.to_string());
+            push!(self.columns, Box::new(Column::new(name.to_string())));
         }
         self
     }
 
+    /// Add an expression as a column.
+    pub fn expression<T>(mut self, expression: T) -> Self where T: Expression + 'static {
+        push!(self.columns, Box::new(expression));
+        self
+    }
+
     /// Add a condition.
     pub fn so_that<T>(mut self, condition: T) -> Self where T: Condition + 'static {
         self.so_that = Some(match self.so_that.take() {
@@ -48,6 +77,12 @@ impl Select {
         self
     }
 
+    /// Add a named window.
+    pub fn window<T: ToString>(mut self, name: T, window: Window) -> Self {
+        push!(self.windows, (name.to_string(), window));
+        self
+    }
+
     /// Add an order.
     pub fn order_by<T>(mut self, expression: T) -> Self where T: Expression + 'static {
         self.order_by = Some(match self.order_by.take() {
@@ -62,17 +97,49 @@ impl Select {
         self.limit = Some(count);
         self
     }
-}
 
-impl Statement for Select {
-    fn compile(&self) -> Result<String> {
+    /// Lock the selected rows for updating.
+    pub fn for_update(mut self) -> Self {
+        self.lock = Some(Lock::Update);
+        self
+    }
+
+    /// Lock the selected rows for sharing.
+    pub fn for_share(mut self) -> Self {
+        self.lock = Some(Lock::Share);
+        self
+    }
+
+    /// Mark that it should fail instead of waiting for locked rows.
+    pub fn nowait(mut self) -> Self {
+        self.wait = Some(Wait::NoWait);
+        self
+    }
+
+    /// Mark that it should skip locked rows instead of waiting for them.
+    pub fn skip_locked(mut self) -> Self {
+        self.wait = Some(Wait::SkipLocked);
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn width(&self) -> Option<usize> {
+        self.columns.as_ref().map(|columns| columns.len())
+    }
+
+    #[doc(hidden)]
+    pub fn compile_filtered(&self, context: &mut Context) -> Result<String> {
+        self.build(context, true)
+    }
+
+    fn build(&self, context: &mut Context, filtered: bool) -> Result<String> {
         let mut buffer = Buffer::new();
         buffer.push("SELECT");
         if let &Some(ref columns) = &self.columns {
             buffer.push({
                 let mut buffer = Buffer::new();
                 for column in columns {
-                    buffer.push(format!("`{}`", column));
+                    buffer.push(try!(column.compile_with(context)));
                 }
                 buffer.join(", ")
             });
@@ -80,22 +147,74 @@ impl Statement for Select {
             buffer.push("*");
         }
         buffer.push("FROM");
-        buffer.push(format!("`{}`", some!(self.table)));
+        buffer.push({
+            let mut buffer = Buffer::new();
+            if let Some(ref table) = self.table {
+                buffer.push(context.quote(table));
+            }
+            if let Some(ref sources) = self.sources {
+                for source in sources {
+                    buffer.push(try!(source.compile_with(context)));
+                }
+            }
+            if buffer.len() == 0 {
+                raise!("expected “table” to be set");
+            }
+            buffer.join(", ")
+        });
         if let &Some(ref clause) = &self.so_that {
-            buffer.push(try!(clause.compile()));
+            buffer.push(try!(clause.compile_with(context)));
+        } else if filtered {
+            buffer.push("WHERE true");
+        }
+        if let Some(ref windows) = self.windows {
+            buffer.push({
+                let mut buffer = Buffer::new();
+                for &(ref name, ref window) in windows {
+                    buffer.push(format!("{} AS ({})", context.quote(name),
+                                                      try!(window.compile_with(context))));
+                }
+                format!("WINDOW {}", buffer.join(", "))
+            });
         }
         if let Some(ref clause) = self.order_by {
-            buffer.push(try!(clause.compile()));
+            buffer.push(try!(clause.compile_with(context)));
         }
         if let Some(count) = self.limit {
             buffer.push(format!("LIMIT {}", count));
         }
+        if let Some(lock) = self.lock {
+            if context.dialect() == Dialect::SQLite {
+                raise!("expected no locking in SQLite");
+            }
+            buffer.push(match lock {
+                Lock::Share => "FOR SHARE",
+                Lock::Update => "FOR UPDATE",
+            });
+            if let Some(wait) = self.wait {
+                buffer.push(match wait {
+                    Wait::NoWait => "NOWAIT",
+                    Wait::SkipLocked => "SKIP LOCKED",
+                });
+            }
+        } else if let Some(_) = self.wait {
+            raise!("expected “lock” to be set");
+        }
         Ok(buffer.join(" "))
     }
 }
 
+impl Statement for Select {
+    #[inline]
+    fn compile_with(&self, context: &mut Context) -> Result<String> {
+        self.build(context, false)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use grammar::Context;
+    use grammar::statement::Select;
     use prelude::*;
 
     #[test]
@@ -110,6 +229,12 @@ mod tests {
         assert_eq!(statement.compile().unwrap(), "SELECT `bar`, `baz` FROM `foo`");
     }
 
+    #[test]
+    fn expression() {
+        let statement = select_from("foo").column("bar").expression(column("baz") * 2);
+        assert_eq!(statement.compile().unwrap(), "SELECT `bar`, `baz` * 2 FROM `foo`");
+    }
+
     #[test]
     fn like() {
         let statement = select_from("foo").so_that(column("bar").like("%baz%"));
@@ -122,9 +247,65 @@ mod tests {
         assert_eq!(statement.compile().unwrap(), "SELECT * FROM `foo` ORDER BY bar, `baz` DESC");
     }
 
+    #[test]
+    fn named_window() {
+        let statement = select_from("foo").expression(rank().over_named("bar"))
+                                          .window("bar", window().order_by(column("baz")));
+
+        assert_eq!(statement.compile().unwrap(),
+                   "SELECT RANK() OVER `bar` FROM `foo` WINDOW `bar` AS (ORDER BY `baz`)");
+    }
+
+    #[test]
+    fn sources() {
+        let statement = select_from("foo").column("value")
+                                          .from(json_each(column("bar")))
+                                          .so_that(column("value").greater_than(42));
+
+        assert_eq!(statement.compile().unwrap(),
+                   "SELECT `value` FROM `foo`, json_each(`bar`) WHERE `value` > 42");
+
+        let statement = Select::default().from(json_each(value("[1, 2]")));
+        assert_eq!(statement.compile().unwrap(), "SELECT * FROM json_each('[1, 2]')");
+
+        assert!(Select::default().compile().is_err());
+    }
+
     #[test]
     fn limit() {
         let statement = select_from("foo").limit(10);
         assert_eq!(statement.compile().unwrap(), "SELECT * FROM `foo` LIMIT 10");
     }
+
+    #[test]
+    fn lock() {
+        let statement = select_from("foo").limit(1).for_update().skip_locked();
+        assert!(statement.compile().is_err());
+
+        let mut context = Context::new(Dialect::PostgreSQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "SELECT * FROM \"foo\" LIMIT 1 FOR UPDATE SKIP LOCKED");
+
+        let statement = select_from("foo").for_share().nowait();
+        let mut context = Context::new(Dialect::MySQL);
+        assert_eq!(statement.compile_with(&mut context).unwrap(),
+                   "SELECT * FROM `foo` FOR SHARE NOWAIT");
+
+        let statement = select_from("foo").nowait();
+        assert!(statement.compile_with(&mut context).is_err());
+    }
+
+    #[test]
+    fn bound() {
+        let statement = select_from("foo").so_that(column("bar").equal(value("baz")))
+                                          .so_that(column("qux").like("%quux%"));
+
+        assert_eq!(statement.compile_bound(Dialect::SQLite).unwrap(),
+                   ("SELECT * FROM `foo` WHERE `bar` = ? AND `qux` LIKE ?".to_string(),
+                    vec![value("baz"), value("%quux%")]));
+
+        assert_eq!(statement.compile_bound(Dialect::PostgreSQL).unwrap(),
+                   ("SELECT * FROM \"foo\" WHERE \"bar\" = $1 AND \"qux\" LIKE $2".to_string(),
+                    vec![value("baz"), value("%quux%")]));
+    }
 }
@@ -1,7 +1,42 @@
 //! The language.
 
-use grammar::definition::Column;
-use grammar::statement::{CreateTable, Delete, Insert, Select};
+use Type;
+use grammar::Expression;
+use grammar::clause::{Excluded, OnConflict};
+use grammar::definition::{Column, Window};
+use grammar::expression::{Case, Cast, Function, Json, JsonArray, JsonObject, Named};
+use grammar::expression::{Placeholder, Value};
+use grammar::statement::{AlterTable, CreateIndex, CreateTable, CreateView, CreateVirtualTable};
+use grammar::statement::{Delete, DropIndex, DropTable, DropView, Insert, Row, Select};
+
+pub use grammar::expression::function::{abs, bm25, coalesce, current_date, current_time};
+pub use grammar::expression::function::{current_timestamp, date, dense_rank, highlight, length};
+pub use grammar::expression::function::{lower, nullif, rank, round, row_number, snippet, substr};
+pub use grammar::expression::function::upper;
+
+/// Create an `ALTER TABLE` statement.
+#[inline]
+pub fn alter_table<T: ToString>(name: T) -> AlterTable {
+    AlterTable::new(name)
+}
+
+/// Create a `CASE` expression.
+#[inline]
+pub fn case() -> Case {
+    Case::new()
+}
+
+/// Create a `CASE` expression comparing an operand with values.
+#[inline]
+pub fn case_of<T>(operand: T) -> Case where T: Expression + 'static {
+    Case::new().operand(operand)
+}
+
+/// Create a `CAST` expression.
+#[inline]
+pub fn cast<T>(expression: T, kind: Type) -> Cast where T: Expression + 'static {
+    Cast::new(expression, kind)
+}
 
 /// Create a column definition.
 #[inline]
@@ -9,26 +44,134 @@ pub fn column<T: ToString>(name: T) -> Column {
     Column::new(name)
 }
 
+/// Create a `CREATE INDEX` statement.
+#[inline]
+pub fn create_index<T: ToString>(name: T) -> CreateIndex {
+    CreateIndex::new(name)
+}
+
 /// Create a `CREATE TABLE` statement.
 #[inline]
 pub fn create_table<T: ToString>(name: T) -> CreateTable {
     CreateTable::new(name)
 }
 
+/// Create a `CREATE VIEW` statement.
+#[inline]
+pub fn create_view<T: ToString>(name: T) -> CreateView {
+    CreateView::new(name)
+}
+
+/// Create a `CREATE VIRTUAL TABLE` statement for a full-text index of SQLite.
+#[inline]
+pub fn create_virtual_table<T: ToString>(name: T) -> CreateVirtualTable {
+    CreateVirtualTable::new(name)
+}
+
 /// Create a `DELETE` statement.
 #[inline]
 pub fn delete_from<T: ToString>(table: T) -> Delete {
     Delete::new(table)
 }
 
+/// Create a `DROP INDEX` statement.
+#[inline]
+pub fn drop_index<T: ToString>(name: T) -> DropIndex {
+    DropIndex::new(name)
+}
+
+/// Create a `DROP TABLE` statement.
+#[inline]
+pub fn drop_table<T: ToString>(name: T) -> DropTable {
+    DropTable::new(name)
+}
+
+/// Create a `DROP VIEW` statement.
+#[inline]
+pub fn drop_view<T: ToString>(name: T) -> DropView {
+    DropView::new(name)
+}
+
+/// Create a reference to a value that was proposed for insertion.
+#[inline]
+pub fn excluded<T: ToString>(column: T) -> Excluded {
+    Excluded::new(column)
+}
+
+/// Create a call to a user-defined function.
+#[inline]
+pub fn function<T: ToString>(name: T) -> Function {
+    Function::new(name)
+}
+
 /// Create an `INSERT` statement.
 #[inline]
 pub fn insert_into<T: ToString>(table: T) -> Insert {
     Insert::new(table)
 }
 
+/// Create a JSON array.
+#[inline]
+pub fn json_array() -> JsonArray {
+    JsonArray::new()
+}
+
+/// Create a table-valued source iterating over a JSON document.
+#[inline]
+pub fn json_each<T>(expression: T) -> Json where T: Expression + 'static {
+    Json::each(expression)
+}
+
+/// Create a JSON object.
+#[inline]
+pub fn json_object() -> JsonObject {
+    JsonObject::new()
+}
+
+/// Create a named placeholder.
+#[inline]
+pub fn named<T: ToString>(name: T) -> Named {
+    Named::new(name)
+}
+
+/// Create an `ON CONFLICT` clause.
+#[inline]
+pub fn on_conflict<T: ToString>(columns: &[T]) -> OnConflict {
+    OnConflict::new(columns)
+}
+
+/// Create a placeholder.
+#[inline]
+pub fn placeholder() -> Placeholder {
+    Placeholder
+}
+
+/// Create a `REPLACE` statement.
+#[inline]
+pub fn replace_into<T: ToString>(table: T) -> Insert {
+    Insert::new(table).replace()
+}
+
+/// Create a row of values.
+#[inline]
+pub fn row() -> Row {
+    Row::default()
+}
+
 /// Create a `SELECT` statement.
 #[inline]
 pub fn select_from<T: ToString>(table: T) -> Select {
     Select::new(table)
 }
+
+/// Create a value.
+#[inline]
+pub fn value<T: Into<Value>>(value: T) -> Value {
+    value.into()
+}
+
+/// Create a window definition.
+#[inline]
+pub fn window() -> Window {
+    Window::new()
+}
@@ -14,6 +14,9 @@
 //! // DELETE FROM `users`
 //! println!("{}", delete_from("users").compile().unwrap());
 //!
+//! // DROP TABLE IF EXISTS `users`
+//! println!("{}", drop_table("users").if_exists().compile().unwrap());
+//!
 //! // INSERT INTO `users` (`id`, `name`) VALUES (?, ?), (?, ?)
 //! println!("{}", insert_into("users").columns(&["id", "name"]).batch(2)
 //!                                    .compile().unwrap());
@@ -33,6 +36,17 @@
 
 use std::{error, fmt, result};
 
+/// A dialect.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Dialect {
+    /// The MySQL dialect.
+    MySQL,
+    /// The PostgreSQL dialect.
+    PostgreSQL,
+    /// The SQLite dialect.
+    SQLite,
+}
+
 /// An error.
 pub struct Error(String);
 
@@ -85,6 +99,13 @@ pub trait Typable where Self: Sized {
     }
 }
 
+impl Default for Dialect {
+    #[inline]
+    fn default() -> Self {
+        Dialect::SQLite
+    }
+}
+
 impl fmt::Debug for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         self.0.fmt(formatter)
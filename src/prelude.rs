@@ -1,11 +1,25 @@
 //! Reexports of functions, traits, and types.
 
+pub use Dialect;
 pub use Typable;
 pub use Type;
 
 pub use grammar::Statement;
 pub use grammar::clause::Order;
 pub use grammar::clause::Orderable;
+pub use grammar::definition::Bound;
+pub use grammar::expression::Extractable;
+pub use grammar::expression::Operable;
+pub use grammar::expression::Value;
+pub use grammar::expression::Windowable;
+pub use grammar::operation::Comparable;
+pub use grammar::operation::Containable;
+pub use grammar::operation::Globbable;
+pub use grammar::operation::ILikable;
 pub use grammar::operation::Likable;
+pub use grammar::operation::Matchable;
+pub use grammar::operation::Regexable;
+pub use grammar::operation::Similarable;
+pub use grammar::statement::Resolution;
 
 pub use language::*;